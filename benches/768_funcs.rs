@@ -1,9 +1,10 @@
-// #![feature(generic_const_exprs)]
+#![allow(clippy::upper_case_acronyms)]
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use kemkem::mlkem::*;
 use kemkem::params::*;
+use kemkem::serialize::*;
 type PARAMS = MlKem768;
 
 fn bench_keygen(criterion: &mut Criterion) {
@@ -33,5 +34,74 @@ fn bench_decaps(criterion: &mut Criterion) {
     });
 }
 
-criterion_group!(bench_768, bench_keygen, bench_encaps, bench_decaps);
+// A long lived key, prepared once and reused for every call
+fn bench_prepared(criterion: &mut Criterion) {
+    let (ek, dk) = key_gen::<PARAMS>();
+    let (ek, dk) = (ek.prepare(), dk.prepare());
+
+    criterion.bench_function("Encaps (prepared)", |b| b.iter(|| {
+        let (_key, _c) = ek.encaps();
+    }));
+
+    criterion.bench_function("Decaps (prepared)", |b| {
+        b.iter_batched(|| ek.encaps(), |(key, c)| {
+            let key_prime = dk.decaps(&c);
+            assert_eq!(key, key_prime);
+        }, criterion::BatchSize::SmallInput);
+    });
+}
+
+// ByteEncode / ByteDecode of the keys and cyphertext, deserializing includes the modulus checks
+fn bench_serialize(criterion: &mut Criterion) {
+    let (ek, dk) = key_gen::<PARAMS>();
+    let (_key, c) = encaps::<PARAMS>(ek.clone());
+    let (ek_bytes, dk_bytes, c_bytes) = (ek.serialize(), dk.serialize(), c.serialize());
+
+    criterion.bench_function("Serialize ek", |b| b.iter(|| ek.serialize()));
+    criterion.bench_function("Serialize dk", |b| b.iter(|| dk.serialize()));
+    criterion.bench_function("Serialize c", |b| b.iter(|| c.serialize()));
+
+    criterion.bench_function("Deserialize ek", |b| b.iter(|| EncapsulationKey::<PARAMS>::try_deserialize(&ek_bytes).unwrap()));
+    criterion.bench_function("Deserialize dk", |b| b.iter(|| DecapsulationKey::<PARAMS>::try_deserialize(&dk_bytes).unwrap()));
+    criterion.bench_function("Deserialize c", |b| b.iter(|| Ciphertext::<PARAMS>::try_deserialize(&c_bytes).unwrap()));
+}
+
+// The ring arithmetic on its own, run with `cargo bench --features bench-internals`
+#[cfg(feature = "bench-internals")]
+fn bench_ring(criterion: &mut Criterion) {
+    use kemkem::internals::*;
+
+    let mut ring = Ring::ZEROES_DEGREE255;
+    for (i, coefficient) in ring.data.iter_mut().enumerate() {
+        *coefficient = ((i * 1021 + 17) % Q as usize) as _;
+    }
+    let mut ring_ntt = ring.clone();
+    ring_ntt.ntt();
+
+    criterion.bench_function("NTT", |b| {
+        b.iter_batched(|| ring.clone(), |mut ring| {
+            ring.ntt();
+            ring
+        }, criterion::BatchSize::SmallInput);
+    });
+
+    criterion.bench_function("NTT^-1", |b| {
+        b.iter_batched(|| ring_ntt.clone(), |mut ring| {
+            ring.inverse_ntt();
+            ring
+        }, criterion::BatchSize::SmallInput);
+    });
+
+    criterion.bench_function("MultiplyNTTs", |b| {
+        b.iter_batched(|| ring_ntt.clone(), |mut ring| {
+            ring.mult(&ring_ntt);
+            ring
+        }, criterion::BatchSize::SmallInput);
+    });
+}
+
+#[cfg(not(feature = "bench-internals"))]
+fn bench_ring(_criterion: &mut Criterion) {}
+
+criterion_group!(bench_768, bench_keygen, bench_encaps, bench_decaps, bench_prepared, bench_serialize, bench_ring);
 criterion_main!(bench_768);
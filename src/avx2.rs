@@ -0,0 +1,427 @@
+//! AVX2 kernels for the ring arithmetic, compression, CBD sampling and the 4-way Keccak-f[1600], picked at runtime when the CPU supports them
+//!
+//! Each kernel computes exactly what its portable counterpart in `ring.rs` / `sample.rs` does (coefficients in [0, q) in and out),
+//! so the backends can be swapped freely. `avx2_test.rs` checks them against each other.
+//!
+//! A ring is handled as 16 vectors of 16 coefficients. NTT layers with a distance of 16 or more work on whole vectors, the last
+//! three (distances 8, 4 and 2) first shuffle a pair of vectors so the low and high halves of each butterfly line up.
+use std::arch::x86_64::*;
+
+use crate::keccak::{StateX4, PI_DESTINATIONS, RHO_OFFSETS, ROUND_CONSTANTS};
+use crate::params;
+use crate::ring::{fqmul, INV_128, QINV, R2, ZETA_POWERS_MULT, ZETA_POWERS_NTT};
+
+const Q: i16 = params::Q as i16;
+
+pub fn available() -> bool {
+    std::arch::is_x86_feature_detected!("avx2")
+}
+
+// The base case multiplication factors widened to 32 bit lanes, already in the Montgomery domain
+const ZETA_POWERS_MULT_32: [i32; 128] = {
+    let mut zeta_powers = [0i32; 128];
+    let mut i = 0;
+    while i < 128 {
+        zeta_powers[i] = ZETA_POWERS_MULT[i] as i32;
+        i += 1;
+    }
+    zeta_powers
+};
+
+// floor(x / q) = (x * COMPRESS_V) >> COMPRESS_SHIFT for every x compress can see, x < 3328 * 2^11 + q / 2
+const COMPRESS_SHIFT: i32 = 33;
+const COMPRESS_V: i64 = ((1 << COMPRESS_SHIFT) + Q as i64 - 1) / Q as i64;
+
+#[target_feature(enable = "avx2")]
+fn load(data: &[i16; 256]) -> [__m256i; 16] {
+    // SAFETY: each load reads 16 coefficients inside the array, loadu has no alignment requirement
+    core::array::from_fn(|i| unsafe { _mm256_loadu_si256(data[16 * i..].as_ptr() as *const __m256i) })
+}
+
+#[target_feature(enable = "avx2")]
+fn store(data: &mut [i16; 256], vectors: &[__m256i; 16]) {
+    for (i, vector) in vectors.iter().enumerate() {
+        // SAFETY: each store writes 16 coefficients inside the array
+        unsafe { _mm256_storeu_si256(data[16 * i..].as_mut_ptr() as *mut __m256i, *vector) };
+    }
+}
+
+// Montgomery multiplication of each lane, a * b * R^-1 mod q in (-q, q), the same value as the portable fqmul
+#[target_feature(enable = "avx2")]
+fn fqmul_16(a: __m256i, b: __m256i) -> __m256i {
+    let t = _mm256_mullo_epi16(_mm256_mullo_epi16(a, b), _mm256_set1_epi16(QINV));
+    // The low halves of a * b and t * q are equal, so subtracting the high halves is exact
+    _mm256_sub_epi16(_mm256_mulhi_epi16(a, b), _mm256_mulhi_epi16(t, _mm256_set1_epi16(Q)))
+}
+
+// Barrett reduction of each lane into (-q, q), rounding the quotient like the portable barrett_reduce
+#[target_feature(enable = "avx2")]
+fn barrett_reduce_16(a: __m256i) -> __m256i {
+    const V: i16 = (((1 << 26) + Q as i32 / 2) / Q as i32) as i16;
+    let t = _mm256_mulhrs_epi16(_mm256_mulhi_epi16(a, _mm256_set1_epi16(V)), _mm256_set1_epi16(1 << 5));
+    _mm256_sub_epi16(a, _mm256_mullo_epi16(t, _mm256_set1_epi16(Q)))
+}
+
+// Maps each lane from (-q, q) to [0, q)
+#[target_feature(enable = "avx2")]
+fn to_canonical_16(a: __m256i) -> __m256i {
+    _mm256_add_epi16(a, _mm256_and_si256(_mm256_srai_epi16::<15>(a), _mm256_set1_epi16(Q)))
+}
+
+#[target_feature(enable = "avx2")]
+fn to_canonical_32(a: __m256i) -> __m256i {
+    _mm256_add_epi32(a, _mm256_and_si256(_mm256_srai_epi32::<31>(a), _mm256_set1_epi32(Q as i32)))
+}
+
+// Montgomery reduction of each 32 bit lane, for |a| < q * 2^15 returns a * R^-1 mod q in (-q, q)
+#[target_feature(enable = "avx2")]
+fn montgomery_reduce_32(a: __m256i) -> __m256i {
+    let t = _mm256_mullo_epi32(a, _mm256_set1_epi32(QINV as i32));
+    let t = _mm256_srai_epi32::<16>(_mm256_slli_epi32::<16>(t));
+    _mm256_srai_epi32::<16>(_mm256_sub_epi32(a, _mm256_mullo_epi32(t, _mm256_set1_epi32(Q as i32))))
+}
+
+// Forward butterfly, (low + zeta * high, low - zeta * high)
+#[target_feature(enable = "avx2")]
+fn butterfly(low: &mut __m256i, high: &mut __m256i, zeta: __m256i) {
+    let t = fqmul_16(zeta, *high);
+    *high = _mm256_sub_epi16(*low, t);
+    *low = _mm256_add_epi16(*low, t);
+}
+
+// Inverse butterfly, (low + high, zeta * (high - low))
+#[target_feature(enable = "avx2")]
+fn inverse_butterfly(low: &mut __m256i, high: &mut __m256i, zeta: __m256i) {
+    let t = *low;
+    *low = _mm256_add_epi16(t, *high);
+    *high = fqmul_16(zeta, _mm256_sub_epi16(*high, t));
+}
+
+// Zetas for one vector of butterflies, `blocks` gives the block of each group of lanes in order and `k` the zeta index of a block
+fn zetas<const N: usize>(blocks: [usize; N], k: impl Fn(usize) -> usize) -> [i16; 16] {
+    core::array::from_fn(|lane| ZETA_POWERS_NTT[k(blocks[lane / (16 / N)])])
+}
+
+#[target_feature(enable = "avx2")]
+fn load_zetas(zetas: [i16; 16]) -> __m256i {
+    // SAFETY: reads the 16 coefficients of the array
+    unsafe { _mm256_loadu_si256(zetas.as_ptr() as *const __m256i) }
+}
+
+// Runs one of the 3 inner layers on the 32 coefficients of a vector pair, blocks are numbered across the whole ring
+#[target_feature(enable = "avx2")]
+fn inner_layer(v: &mut [__m256i], pair: usize, len: usize, k: impl Fn(usize) -> usize, inverse: bool) {
+    let (mut v_0, mut v_1) = (v[0], v[1]);
+
+    // Shuffle the low halves of every butterfly into one vector and the high halves into the other
+    let (mut low, mut high, blocks) = match len {
+        8 => {
+            let b = 2 * pair;
+            (_mm256_permute2x128_si256::<0x20>(v_0, v_1), _mm256_permute2x128_si256::<0x31>(v_0, v_1), zetas([b, b + 1], &k))
+        },
+        4 => {
+            let b = 4 * pair;
+            (_mm256_unpacklo_epi64(v_0, v_1), _mm256_unpackhi_epi64(v_0, v_1), zetas([b, b + 2, b + 1, b + 3], &k))
+        },
+        _ => {
+            let b = 8 * pair;
+            v_0 = _mm256_shuffle_epi32::<0b11_01_10_00>(v_0);
+            v_1 = _mm256_shuffle_epi32::<0b11_01_10_00>(v_1);
+            (_mm256_unpacklo_epi64(v_0, v_1), _mm256_unpackhi_epi64(v_0, v_1), zetas([b, b + 1, b + 4, b + 5, b + 2, b + 3, b + 6, b + 7], &k))
+        }
+    };
+
+    let zeta = load_zetas(blocks);
+    match inverse {
+        false => butterfly(&mut low, &mut high, zeta),
+        true => inverse_butterfly(&mut low, &mut high, zeta)
+    }
+
+    // Each shuffle is undone by applying it again
+    (v[0], v[1]) = match len {
+        8 => (_mm256_permute2x128_si256::<0x20>(low, high), _mm256_permute2x128_si256::<0x31>(low, high)),
+        4 => (_mm256_unpacklo_epi64(low, high), _mm256_unpackhi_epi64(low, high)),
+        _ => (
+            _mm256_shuffle_epi32::<0b11_01_10_00>(_mm256_unpacklo_epi64(low, high)),
+            _mm256_shuffle_epi32::<0b11_01_10_00>(_mm256_unpackhi_epi64(low, high))
+        )
+    };
+}
+
+#[target_feature(enable = "avx2")]
+pub fn ntt(data: &mut [i16; 256]) {
+    let mut v = load(data);
+
+    // Distances 128 to 16, 8 to 1 in vectors
+    let mut k = 1;
+    let mut len = 8;
+    while len >= 1 {
+        for start in (0..16).step_by(2 * len) {
+            let zeta = _mm256_set1_epi16(ZETA_POWERS_NTT[k]);
+            k += 1;
+
+            for j in start..start + len {
+                let (low, high) = v.split_at_mut(j + len);
+                butterfly(&mut low[j], &mut high[0], zeta);
+            }
+        }
+        len /= 2;
+    }
+
+    for len in [8, 4, 2] {
+        for pair in 0..8 {
+            inner_layer(&mut v[2 * pair..2 * pair + 2], pair, len, |b| 128 / len + b, false);
+        }
+    }
+
+    for vector in v.iter_mut() {
+        *vector = to_canonical_16(barrett_reduce_16(*vector));
+    }
+
+    store(data, &v);
+}
+
+#[target_feature(enable = "avx2")]
+pub fn inverse_ntt(data: &mut [i16; 256]) {
+    let mut v = load(data);
+
+    for len in [2, 4, 8] {
+        for pair in 0..8 {
+            inner_layer(&mut v[2 * pair..2 * pair + 2], pair, len, |b| 256 / len - 1 - b, true);
+        }
+    }
+
+    // Same single reduction as the portable code, after the distance 8 layer
+    for vector in v.iter_mut() {
+        *vector = barrett_reduce_16(*vector);
+    }
+
+    // Distances 16 to 128, 1 to 8 in vectors
+    let mut len = 1;
+    while len <= 8 {
+        for (b, start) in (0..16).step_by(2 * len).enumerate() {
+            let zeta = _mm256_set1_epi16(ZETA_POWERS_NTT[16 / len - 1 - b]);
+
+            for j in start..start + len {
+                let (low, high) = v.split_at_mut(j + len);
+                inverse_butterfly(&mut low[j], &mut high[0], zeta);
+            }
+        }
+        len *= 2;
+    }
+
+    let scale = _mm256_set1_epi16(fqmul(INV_128, R2));
+    for vector in v.iter_mut() {
+        *vector = to_canonical_16(fqmul_16(*vector, scale));
+    }
+
+    store(data, &v);
+}
+
+// Multiplication of NTTs, each pair of coefficients (a_0, a_1) sits in one 32 bit lane, 8 pairs per vector
+#[target_feature(enable = "avx2")]
+pub fn mult(a: &mut [i16; 256], b: &[i16; 256]) {
+    let mut v_a = load(a);
+    let v_b = load(b);
+
+    for (i, (v_a, v_b)) in v_a.iter_mut().zip(v_b.iter()).enumerate() {
+        let a_0 = _mm256_srai_epi32::<16>(_mm256_slli_epi32::<16>(*v_a));
+        let a_1 = _mm256_srai_epi32::<16>(*v_a);
+        let b_0 = _mm256_srai_epi32::<16>(_mm256_slli_epi32::<16>(*v_b));
+        let b_1 = _mm256_srai_epi32::<16>(*v_b);
+        // SAFETY: reads the 8 gammas of this vector
+        let gamma = unsafe { _mm256_loadu_si256(ZETA_POWERS_MULT_32[8 * i..].as_ptr() as *const __m256i) };
+
+        // montgomery_reduce(a_1 * b_1) * gamma * R is a_1 * b_1 * gamma, on the same scale as a_0 * b_0
+        let a_1_b_1 = montgomery_reduce_32(_mm256_mullo_epi32(a_1, b_1));
+        let r_1 = _mm256_add_epi32(_mm256_mullo_epi32(a_0, b_0), _mm256_mullo_epi32(a_1_b_1, gamma));
+        let r_2 = _mm256_add_epi32(_mm256_mullo_epi32(a_0, b_1), _mm256_mullo_epi32(a_1, b_0));
+
+        // Reducing leaves a factor R^-1, the multiplication by R^2 removes it
+        let r2 = _mm256_set1_epi32(R2 as i32);
+        let r_1 = to_canonical_32(montgomery_reduce_32(_mm256_mullo_epi32(montgomery_reduce_32(r_1), r2)));
+        let r_2 = to_canonical_32(montgomery_reduce_32(_mm256_mullo_epi32(montgomery_reduce_32(r_2), r2)));
+
+        *v_a = _mm256_or_si256(r_1, _mm256_slli_epi32::<16>(r_2));
+    }
+
+    store(a, &v_a);
+}
+
+// Runs `f` on the coefficients 8 at a time, widened to 32 bit lanes
+#[target_feature(enable = "avx2")]
+fn map_32(data: &mut [i16; 256], f: impl Fn(__m256i) -> __m256i) {
+    for chunk in data.chunks_exact_mut(8) {
+        // SAFETY: reads and writes the 8 coefficients of the chunk
+        unsafe {
+            let x = _mm256_cvtepu16_epi32(_mm_loadu_si128(chunk.as_ptr() as *const __m128i));
+            // Packing works within 128 bit lanes, the permute moves both results into the low half
+            let y = f(x);
+            let packed = _mm256_permute4x64_epi64::<0b00_00_10_00>(_mm256_packus_epi32(y, y));
+            _mm_storeu_si128(chunk.as_mut_ptr() as *mut __m128i, _mm256_castsi256_si128(packed));
+        }
+    }
+}
+
+// Compress_d, round(2^d / q * x) mod 2^d as ((x << d) + q / 2) / q, with the division done by multiplication
+#[target_feature(enable = "avx2")]
+pub fn compress(data: &mut [i16; 256], d: usize) {
+    let shift = _mm_cvtsi32_si128(d as i32);
+    let v = _mm256_set1_epi64x(COMPRESS_V);
+    let mask = _mm256_set1_epi32((1 << d) - 1);
+
+    map_32(data, |x| {
+        let x = _mm256_add_epi32(_mm256_sll_epi32(x, shift), _mm256_set1_epi32(Q as i32 / 2));
+        // mul_epu32 only multiplies the even lanes, the odd ones are shifted down for a second multiplication
+        let even = _mm256_srli_epi64::<COMPRESS_SHIFT>(_mm256_mul_epu32(x, v));
+        let odd = _mm256_srli_epi64::<COMPRESS_SHIFT>(_mm256_mul_epu32(_mm256_srli_epi64::<32>(x), v));
+        let quotient = _mm256_blend_epi32::<0b10101010>(even, _mm256_slli_epi64::<32>(odd));
+        _mm256_and_si256(quotient, mask)
+    });
+}
+
+// Decompress_d, (y * q + 2^(d - 1)) >> d
+#[target_feature(enable = "avx2")]
+pub fn decompress(data: &mut [i16; 256], d: usize) {
+    let shift = _mm_cvtsi32_si128(d as i32);
+    let pow_half = _mm256_set1_epi32(1 << (d - 1));
+
+    map_32(data, |y| {
+        _mm256_srl_epi32(_mm256_add_epi32(_mm256_mullo_epi32(y, _mm256_set1_epi32(Q as i32)), pow_half), shift)
+    });
+}
+
+// SamplePolyCBD_eta, each coefficient is the difference of two sums of eta bits
+#[target_feature(enable = "avx2")]
+pub fn sample_poly_cbd(byte_array: &[u8], eta: usize, f: &mut [i16; 256]) {
+    match eta {
+        2 => cbd_2(byte_array, f),
+        3 => cbd_3(byte_array, f),
+        _ => panic!("Unsupported eta {}", eta)
+    }
+}
+
+// 4 bits (2 + 2) per coefficient, so every byte holds 2 coefficients
+#[target_feature(enable = "avx2")]
+fn cbd_2(byte_array: &[u8], f: &mut [i16; 256]) {
+    debug_assert_eq!(byte_array.len(), 128);
+
+    let m_55 = _mm_set1_epi8(0x55);
+    let m_33 = _mm_set1_epi8(0x33);
+    let m_0f = _mm_set1_epi8(0x0F);
+
+    for (bytes, coefficients) in byte_array.chunks_exact(16).zip(f.chunks_exact_mut(32)) {
+        // SAFETY: reads the 16 bytes of the chunk
+        let v = unsafe { _mm_loadu_si128(bytes.as_ptr() as *const __m128i) };
+
+        // Sums of neighbouring bits, then every nibble holds x (low 2 bits) and y (high 2 bits) of one coefficient
+        let s = _mm_add_epi8(_mm_and_si128(v, m_55), _mm_and_si128(_mm_srli_epi16::<1>(v), m_55));
+        let x = _mm_and_si128(s, m_33);
+        let y = _mm_and_si128(_mm_srli_epi16::<2>(s), m_33);
+
+        let even = _mm_sub_epi8(_mm_and_si128(x, m_0f), _mm_and_si128(y, m_0f));
+        let odd = _mm_sub_epi8(_mm_and_si128(_mm_srli_epi16::<4>(x), m_0f), _mm_and_si128(_mm_srli_epi16::<4>(y), m_0f));
+
+        let low = to_canonical_16(_mm256_cvtepi8_epi16(_mm_unpacklo_epi8(even, odd)));
+        let high = to_canonical_16(_mm256_cvtepi8_epi16(_mm_unpackhi_epi8(even, odd)));
+
+        // SAFETY: writes the 32 coefficients of the chunk
+        unsafe {
+            _mm256_storeu_si256(coefficients.as_mut_ptr() as *mut __m256i, low);
+            _mm256_storeu_si256(coefficients[16..].as_mut_ptr() as *mut __m256i, high);
+        }
+    }
+}
+
+// 6 bits (3 + 3) per coefficient, so every 3 bytes hold 4 coefficients, spread over one 32 bit lane each
+#[target_feature(enable = "avx2")]
+fn cbd_3(byte_array: &[u8], f: &mut [i16; 256]) {
+    debug_assert_eq!(byte_array.len(), 192);
+
+    // Every load reads 32 bytes but only uses 24, the copy keeps the last one inside the buffer
+    let mut padded = crate::secret::Secret::new([0u8; 200]);
+    padded[..192].copy_from_slice(byte_array);
+
+    let spread = _mm256_setr_epi8(
+        0, 1, 2, -1, 3, 4, 5, -1, 6, 7, 8, -1, 9, 10, 11, -1,
+        4, 5, 6, -1, 7, 8, 9, -1, 10, 11, 12, -1, 13, 14, 15, -1
+    );
+    let m_249 = _mm256_set1_epi32(0x249249);
+    let m_7 = _mm256_set1_epi32(7);
+
+    for (i, coefficients) in f.chunks_exact_mut(32).enumerate() {
+        // SAFETY: reads 32 bytes at 24 * i, at most 200 for i = 7
+        let v = unsafe { _mm256_loadu_si256(padded[24 * i..].as_ptr() as *const __m256i) };
+        // Bytes 0..16 in the low half and 8..24 in the high half, then 3 bytes into each 32 bit lane
+        let v = _mm256_shuffle_epi8(_mm256_permute4x64_epi64::<0b10_01_01_00>(v), spread);
+
+        // Sums of 3 neighbouring bits, every 3 bit field is x or y of a coefficient
+        let s = _mm256_add_epi32(
+            _mm256_add_epi32(_mm256_and_si256(v, m_249), _mm256_and_si256(_mm256_srli_epi32::<1>(v), m_249)),
+            _mm256_and_si256(_mm256_srli_epi32::<2>(v), m_249)
+        );
+
+        let field = |shift: i32| _mm256_and_si256(_mm256_srl_epi32(s, _mm_cvtsi32_si128(shift)), m_7);
+        let c: [__m256i; 4] = core::array::from_fn(|j| _mm256_sub_epi32(field(6 * j as i32), field(6 * j as i32 + 3)));
+
+        // Two coefficients per 32 bit lane, then interleave the lanes back into order
+        let low_mask = _mm256_set1_epi32(0xFFFF);
+        let c_01 = _mm256_or_si256(_mm256_and_si256(c[0], low_mask), _mm256_slli_epi32::<16>(c[1]));
+        let c_23 = _mm256_or_si256(_mm256_and_si256(c[2], low_mask), _mm256_slli_epi32::<16>(c[3]));
+        let low = _mm256_unpacklo_epi32(c_01, c_23);
+        let high = _mm256_unpackhi_epi32(c_01, c_23);
+
+        // SAFETY: writes the 32 coefficients of the chunk
+        unsafe {
+            _mm256_storeu_si256(coefficients.as_mut_ptr() as *mut __m256i, to_canonical_16(_mm256_permute2x128_si256::<0x20>(low, high)));
+            _mm256_storeu_si256(coefficients[16..].as_mut_ptr() as *mut __m256i, to_canonical_16(_mm256_permute2x128_si256::<0x31>(low, high)));
+        }
+    }
+}
+
+// Rotation of each 64 bit lane, shifts by 64 give zero so an offset of 0 works too
+#[target_feature(enable = "avx2")]
+fn rotate_left_64(a: __m256i, n: u32) -> __m256i {
+    let left = _mm256_sll_epi64(a, _mm_cvtsi32_si128(n as i32));
+    let right = _mm256_srl_epi64(a, _mm_cvtsi32_si128(64 - n as i32));
+    _mm256_or_si256(left, right)
+}
+
+// Keccak-f[1600] on 4 states, one per 64 bit lane, the same steps as keccak_f1600_x4_portable
+#[target_feature(enable = "avx2")]
+pub fn keccak_f1600_x4(state: &mut StateX4) {
+    // SAFETY: each load reads the 4 lanes of one entry
+    let mut a: [__m256i; 25] = core::array::from_fn(|i| unsafe { _mm256_loadu_si256(state[i].as_ptr() as *const __m256i) });
+
+    for round_constant in ROUND_CONSTANTS {
+        let c: [__m256i; 5] = core::array::from_fn(|x| {
+            (1..5).fold(a[x], |parity, y| _mm256_xor_si256(parity, a[x + 5 * y]))
+        });
+        for x in 0..5 {
+            let d = _mm256_xor_si256(c[(x + 4) % 5], rotate_left_64(c[(x + 1) % 5], 1));
+            for y in 0..5 {
+                a[x + 5 * y] = _mm256_xor_si256(a[x + 5 * y], d);
+            }
+        }
+
+        let mut b = [_mm256_setzero_si256(); 25];
+        for i in 0..25 {
+            b[PI_DESTINATIONS[i]] = rotate_left_64(a[i], RHO_OFFSETS[i]);
+        }
+
+        for y in 0..5 {
+            for x in 0..5 {
+                // andnot(a, b) is !a & b
+                let not_and = _mm256_andnot_si256(b[(x + 1) % 5 + 5 * y], b[(x + 2) % 5 + 5 * y]);
+                a[x + 5 * y] = _mm256_xor_si256(b[x + 5 * y], not_and);
+            }
+        }
+
+        a[0] = _mm256_xor_si256(a[0], _mm256_set1_epi64x(round_constant as i64));
+    }
+
+    for (lanes, vector) in state.iter_mut().zip(a) {
+        // SAFETY: writes the 4 lanes of one entry
+        unsafe { _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, vector) };
+    }
+}
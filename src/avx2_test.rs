@@ -0,0 +1,129 @@
+// Differential tests, the AVX2 kernels against the portable ones on random inputs
+use rand_core::{OsRng, RngCore};
+
+use crate::avx2;
+use crate::keccak::*;
+use crate::params;
+use crate::ring::*;
+use crate::sample::sample_poly_cbd_portable;
+
+const ROUNDS: usize = 1000;
+
+// Uniform coefficients in [0, modulus)
+fn random_coefficients(modulus: u32) -> [i16; 256] {
+    core::array::from_fn(|_| (OsRng.next_u32() % modulus) as i16)
+}
+
+// Inputs with every coefficient at the edge of the range, where reductions are most likely to be off by one
+fn edge_coefficients() -> [[i16; 256]; 3] {
+    [[0; 256], [params::Q as i16 - 1; 256], core::array::from_fn(|i| if i % 2 == 0 { 0 } else { params::Q as i16 - 1 })]
+}
+
+fn inputs(modulus: u32) -> impl Iterator<Item = [i16; 256]> {
+    edge_coefficients().into_iter().filter(move |data| data.iter().all(|&c| (c as u32) < modulus)).chain((0..ROUNDS).map(move |_| random_coefficients(modulus)))
+}
+
+mod test {
+    use super::*;
+
+    #[test]
+    fn avx2_ntt() {
+        if !avx2::available() {
+            return;
+        }
+
+        for data in inputs(params::Q32) {
+            let (mut portable, mut vectorized) = (data, data);
+            ntt_portable(&mut portable);
+            // SAFETY: AVX2 support is checked above
+            unsafe { avx2::ntt(&mut vectorized) };
+            assert_eq!(portable, vectorized, "{:?}", data);
+
+            inverse_ntt_portable(&mut portable);
+            // SAFETY: AVX2 support is checked above
+            unsafe { avx2::inverse_ntt(&mut vectorized) };
+            assert_eq!(portable, vectorized, "{:?}", data);
+            assert_eq!(portable, data);
+        }
+    }
+
+    #[test]
+    fn avx2_mult() {
+        if !avx2::available() {
+            return;
+        }
+
+        for (a, b) in inputs(params::Q32).zip(inputs(params::Q32).skip(1)) {
+            let (mut portable, mut vectorized) = (a, a);
+            mult_portable(&mut portable, &b);
+            // SAFETY: AVX2 support is checked above
+            unsafe { avx2::mult(&mut vectorized, &b) };
+            assert_eq!(portable, vectorized, "{:?} {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn avx2_compression() {
+        if !avx2::available() {
+            return;
+        }
+
+        for d in [1, 4, 5, 10, 11] {
+            for data in inputs(params::Q32) {
+                let (mut portable, mut vectorized) = (data, data);
+                compress_portable(&mut portable, d);
+                // SAFETY: AVX2 support is checked above
+                unsafe { avx2::compress(&mut vectorized, d) };
+                assert_eq!(portable, vectorized, "d = {}, {:?}", d, data);
+            }
+
+            for data in inputs(1 << d) {
+                let (mut portable, mut vectorized) = (data, data);
+                decompress_portable(&mut portable, d);
+                // SAFETY: AVX2 support is checked above
+                unsafe { avx2::decompress(&mut vectorized, d) };
+                assert_eq!(portable, vectorized, "d = {}, {:?}", d, data);
+            }
+        }
+    }
+
+    #[test]
+    fn avx2_sample_poly_cbd() {
+        if !avx2::available() {
+            return;
+        }
+
+        for eta in [2, 3] {
+            let edges = [vec![0x00; 64 * eta], vec![0xFF; 64 * eta], vec![0x0F; 64 * eta], vec![0xF0; 64 * eta]];
+            let random = (0..ROUNDS).map(|_| {
+                let mut bytes = vec![0u8; 64 * eta];
+                OsRng.fill_bytes(&mut bytes);
+                bytes
+            });
+
+            for bytes in edges.into_iter().chain(random) {
+                let (mut portable, mut vectorized) = ([0i16; 256], [0i16; 256]);
+                sample_poly_cbd_portable(&bytes, eta, &mut portable);
+                // SAFETY: AVX2 support is checked above
+                unsafe { avx2::sample_poly_cbd(&bytes, eta, &mut vectorized) };
+                assert_eq!(portable, vectorized, "eta = {}, {:?}", eta, bytes);
+            }
+        }
+    }
+
+    #[test]
+    fn avx2_keccak_f1600_x4() {
+        if !avx2::available() {
+            return;
+        }
+
+        for _ in 0..ROUNDS {
+            let state: StateX4 = core::array::from_fn(|_| core::array::from_fn(|_| OsRng.next_u64()));
+            let (mut portable, mut vectorized) = (state, state);
+            keccak_f1600_x4_portable(&mut portable);
+            // SAFETY: AVX2 support is checked above
+            unsafe { avx2::keccak_f1600_x4(&mut vectorized) };
+            assert_eq!(portable, vectorized, "{:?}", state);
+        }
+    }
+}
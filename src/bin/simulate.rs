@@ -1,6 +1,4 @@
 //Runs the algorithim one time
-#![allow(incomplete_features)]
-#![feature(generic_const_exprs)]
 
 use kemkem::{mlkem::*, params::*};
 
@@ -18,15 +16,7 @@ fn main() {
 }
 
 
-fn simulate<PARAMS: MlKemParams>() where
-    [(); 768 * PARAMS::K + 96]: , 
-    [(); PARAMS::ETA_1]: ,
-    [(); PARAMS::ETA_2]: ,
-    [(); 384 * PARAMS::K + 32]: ,
-    [(); 32*(PARAMS::D_U * PARAMS::K + PARAMS::D_V)]: ,
-    [(); 64 * PARAMS::ETA_1]: ,
-    [(); 64 * PARAMS::ETA_2]:
-{
+fn simulate<PARAMS: MlKemParams>() {
     let (ek, dk) = key_gen::<PARAMS>();
 
     let (key, c) = encaps::<PARAMS>(ek);
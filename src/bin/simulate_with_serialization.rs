@@ -1,6 +1,4 @@
 //Runs the algorithim one time
-#![allow(incomplete_features)]
-#![feature(generic_const_exprs)]
 
 use kemkem::{mlkem::*, params::*, serialize::*};
 
@@ -18,15 +16,7 @@ fn main() {
 }
 
 
-fn simulate<PARAMS: MlKemParams>() where
-    [(); 768 * PARAMS::K + 96]: , 
-    [(); PARAMS::ETA_1]: ,
-    [(); PARAMS::ETA_2]: ,
-    [(); 384 * PARAMS::K + 32]: ,
-    [(); 32*(PARAMS::D_U * PARAMS::K + PARAMS::D_V)]: ,
-    [(); 64 * PARAMS::ETA_1]: ,
-    [(); 64 * PARAMS::ETA_2]:
-{   
+fn simulate<PARAMS: MlKemParams>() {   
     // Party A
     let (ek, dk) = key_gen::<PARAMS>();
 
@@ -34,15 +24,15 @@ fn simulate<PARAMS: MlKemParams>() where
     let dk = dk.serialize();
 
     // Party B
-    let ek = MlKemEncapsulationKey::<{PARAMS::K}>::deserialize(&ek);
+    let ek = EncapsulationKey::<PARAMS>::deserialize(&ek);
     
     let (key, c) = encaps::<PARAMS>(ek);
 
     let c = c.serialize();
 
     // Party A
-    let c = MlKemCyphertext::<{PARAMS::K}, {PARAMS::D_U}, {PARAMS::D_V}>::deserialize(&c);
-    let dk = MlKemDecapsulationKey::<{PARAMS::K}>::deserialize(&dk);
+    let c = Ciphertext::<PARAMS>::deserialize(&c);
+    let dk = DecapsulationKey::<PARAMS>::deserialize(&dk);
 
     let key_prime = decaps::<PARAMS>(c, dk);
 
@@ -1,9 +1,10 @@
 use sha3::{digest::{core_api::XofReaderCoreWrapper, ExtendableOutput, Update, XofReader}, Digest, Sha3_256, Sha3_512, Shake128ReaderCore, Shake256};
-use rand::{RngCore, SeedableRng};
-use rand::rngs::StdRng;
+use rand_core::CryptoRngCore;
 
-pub fn random_bytes<const N: usize> () -> [u8; N] {
-    let mut rng = StdRng::from_entropy();
+use crate::keccak;
+use crate::secret::{Secret, Wipe};
+
+pub fn random_bytes<const N: usize> (rng: &mut impl CryptoRngCore) -> [u8; N] {
     let mut res = [0u8; N];
     rng.fill_bytes(&mut res);
     res
@@ -12,10 +13,14 @@ pub fn random_bytes<const N: usize> () -> [u8; N] {
 pub fn g<const L: usize>(c: &[u8; L]) -> ([u8; 32], [u8; 32]) {
     let mut hasher = Sha3_512::new();
     Digest::update(&mut hasher, c);
-    let output = hasher.finalize();
+    let mut output = hasher.finalize();
 
     let (a,b) = output.split_at(32);
-    (a.try_into().unwrap(), b.try_into().unwrap())
+    let res = (a.try_into().unwrap(), b.try_into().unwrap());
+
+    // Both halves are secret wherever G is used
+    output.as_mut_slice().wipe();
+    res
 }
 
 pub fn h(s: &Vec<u8>) -> [u8; 32] {
@@ -23,10 +28,11 @@ pub fn h(s: &Vec<u8>) -> [u8; 32] {
     Digest::update(&mut hasher, s);
     let output = hasher.finalize();
 
-    output.try_into().unwrap()
+    output.into()
 }
 
 pub fn j(s: Vec<u8>) -> [u8; 32] {
+    let s = Secret::new(s); // Always contains z or the shared key
     let mut hasher = Shake256::default();
     hasher.update(&s);
     let mut reader = hasher.finalize_xof();
@@ -36,7 +42,7 @@ pub fn j(s: Vec<u8>) -> [u8; 32] {
     res
 }
 
-pub fn prf<const ETA: usize>(s: &[u8; 32], b: u8) -> [u8; 64 * ETA] 
+pub fn prf(s: &[u8; 32], b: u8, eta: usize) -> Vec<u8>
 {
     let mut hasher = Shake256::default();
     hasher.update(s);
@@ -44,29 +50,11 @@ pub fn prf<const ETA: usize>(s: &[u8; 32], b: u8) -> [u8; 64 * ETA]
     
     let mut reader = hasher.finalize_xof();
     
-    let mut res = [0u8; 64 * ETA];
+    let mut res = vec![0u8; 64 * eta];
     XofReader::read(&mut reader, &mut res);
     res
 }
 
-// Optimization Attempt
-// pub fn prf_2(s: &[u8; 32], b: u8) -> [u32; 32]
-// {
-//     let mut hasher = Shake256::default();
-//     hasher.update(s);
-//     hasher.update(&[b]);
-    
-//     let mut reader = hasher.finalize_xof();
-    
-//     let mut res = U32U8Union { u32: [0u32; 32] };
-//     unsafe { 
-//         XofReader::read(&mut reader, &mut res.u8);
-//         res.u32
-//     }
-// }
-
-
-
 pub struct XOF {
     reader: XofReaderCoreWrapper<Shake128ReaderCore>
 }
@@ -81,7 +69,49 @@ impl XOF {
         }
     }
 
-    pub fn get_3_bytes(&mut self, buf: &mut [u8; 3]) {
+    pub fn read_block(&mut self, buf: &mut [u8; XOF_BLOCK]) {
         XofReader::read(&mut self.reader, buf);
     }
+}
+
+// The SHAKE128 rate, the XOFs are read a whole block at a time
+pub const XOF_BLOCK: usize = 168;
+
+/// 4 XOF streams XOF(p, i, j) run side by side with a 4-way Keccak-f[1600]
+pub struct XOFx4 {
+    state: keccak::StateX4
+}
+
+impl XOFx4 {
+    pub fn new(p: &[u8; 32], indices: [(u8, u8); 4]) -> XOFx4 {
+        let mut state = [[0u64; 4]; 25];
+
+        // p || i || j fits in one block, padded with SHAKE's domain separator 1111 and the final bit of pad10*1
+        for (l, (i, j)) in indices.into_iter().enumerate() {
+            let mut block = [0u8; XOF_BLOCK];
+            block[..32].copy_from_slice(p);
+            block[32] = i;
+            block[33] = j;
+            block[34] = 0x1F;
+            block[XOF_BLOCK - 1] |= 0x80;
+
+            for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+                lane[l] ^= u64::from_le_bytes(bytes.try_into().unwrap());
+            }
+        }
+
+        XOFx4 { state }
+    }
+
+    pub fn read_blocks(&mut self) -> [[u8; XOF_BLOCK]; 4] {
+        keccak::keccak_f1600_x4(&mut self.state);
+
+        let mut blocks = [[0u8; XOF_BLOCK]; 4];
+        for (l, block) in blocks.iter_mut().enumerate() {
+            for (lane, bytes) in self.state.iter().zip(block.chunks_exact_mut(8)) {
+                bytes.copy_from_slice(&lane[l].to_le_bytes());
+            }
+        }
+        blocks
+    }
 }
\ No newline at end of file
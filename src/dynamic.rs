@@ -0,0 +1,327 @@
+//! Parameter sets chosen at runtime
+//!
+//! [MlKemParameterSet] names one of the 3 parameter sets, and the `Dyn` types hold a key or cyphertext of whichever parameter set was chosen, dispatching to the generic functions in [crate::mlkem].
+//!
+//! Their byte form starts with the identifier of their parameter set, so the receiving party can tell which one it was sent
+use bitvec::view::BitView;
+use rand_core::{CryptoRngCore, OsRng};
+
+use crate::error::Error;
+use crate::mlkem::*;
+use crate::params::*;
+use crate::serialize::*;
+
+/// One of the 3 ML-KEM parameter sets, for when the choice is only made at runtime (e.g. negotiated in a handshake)
+///
+/// The discriminant is the identifier byte that starts the byte form of the `Dyn` types
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(u8)]
+pub enum MlKemParameterSet {
+    MlKem512 = 1,
+    MlKem768 = 2,
+    MlKem1024 = 3,
+}
+
+impl MlKemParameterSet {
+    /// The K of the parameter set
+    pub fn k(self) -> usize {
+        match self {
+            MlKemParameterSet::MlKem512 => MlKem512::K,
+            MlKemParameterSet::MlKem768 => MlKem768::K,
+            MlKemParameterSet::MlKem1024 => MlKem1024::K,
+        }
+    }
+
+    /// # ML-KEM.KeyGen
+    ///
+    /// Generates a key pair of this parameter set, see [key_gen]
+    pub fn key_gen(self) -> (DynEncapsulationKey, DynDecapsulationKey) {
+        self.key_gen_with_rng(&mut OsRng)
+    }
+
+    /// Same as [MlKemParameterSet::key_gen], with the randomness drawn from **`rng`**, see [key_gen_with_rng]
+    pub fn key_gen_with_rng(self, rng: &mut impl CryptoRngCore) -> (DynEncapsulationKey, DynDecapsulationKey) {
+        match self {
+            MlKemParameterSet::MlKem512 => {
+                let (ek, dk) = key_gen_with_rng::<MlKem512>(rng);
+                (DynEncapsulationKey::MlKem512(ek), DynDecapsulationKey::MlKem512(dk))
+            },
+            MlKemParameterSet::MlKem768 => {
+                let (ek, dk) = key_gen_with_rng::<MlKem768>(rng);
+                (DynEncapsulationKey::MlKem768(ek), DynDecapsulationKey::MlKem768(dk))
+            },
+            MlKemParameterSet::MlKem1024 => {
+                let (ek, dk) = key_gen_with_rng::<MlKem1024>(rng);
+                (DynEncapsulationKey::MlKem1024(ek), DynDecapsulationKey::MlKem1024(dk))
+            },
+        }
+    }
+}
+
+impl From<MlKemParameterSet> for u8 {
+    fn from(parameter_set: MlKemParameterSet) -> u8 {
+        parameter_set as u8
+    }
+}
+
+impl TryFrom<u8> for MlKemParameterSet {
+    type Error = Error;
+
+    fn try_from(id: u8) -> Result<Self, Error> {
+        match id {
+            1 => Ok(MlKemParameterSet::MlKem512),
+            2 => Ok(MlKemParameterSet::MlKem768),
+            3 => Ok(MlKemParameterSet::MlKem1024),
+            _ => Err(Error::UnknownParameterSet { found: id })
+        }
+    }
+}
+
+// Prefixes a serialized value with the identifier of its parameter set
+fn with_parameter_set(parameter_set: MlKemParameterSet, serialized: &impl MlKemSerialize) -> Vec<u8> {
+    let mut bytes = vec![u8::from(parameter_set)];
+    bytes.extend_from_slice(serialized.serialize().as_raw_slice());
+    bytes
+}
+
+// Splits the identifier off a value's byte form, then deserializes the rest as the parameter set it names
+fn split_parameter_set(bytes: &[u8]) -> Result<(MlKemParameterSet, &[u8]), Error> {
+    match bytes.split_first() {
+        Some((&id, rest)) => Ok((MlKemParameterSet::try_from(id)?, rest)),
+        None => Err(Error::InvalidLength { expected: 1, found: 0 })
+    }
+}
+
+fn deserialize<T: MlKemDeserialize>(bytes: &[u8]) -> Result<T, Error> {
+    T::try_deserialize(&bytes.view_bits::<BitOrder>().to_bitvec())
+}
+
+/// An [EncapsulationKey] of a parameter set chosen at runtime
+#[derive(Clone, PartialEq)]
+pub enum DynEncapsulationKey {
+    MlKem512(EncapsulationKey<MlKem512>),
+    MlKem768(EncapsulationKey<MlKem768>),
+    MlKem1024(EncapsulationKey<MlKem1024>),
+}
+
+impl DynEncapsulationKey {
+    /// The parameter set of the key
+    pub fn parameter_set(&self) -> MlKemParameterSet {
+        match self {
+            DynEncapsulationKey::MlKem512(_) => MlKemParameterSet::MlKem512,
+            DynEncapsulationKey::MlKem768(_) => MlKemParameterSet::MlKem768,
+            DynEncapsulationKey::MlKem1024(_) => MlKemParameterSet::MlKem1024,
+        }
+    }
+
+    /// # ML-KEM.Encaps
+    ///
+    /// **Returns** a tuple containing the shared key (Party B's copy) and the cyphertext to be sent back, see [encaps]
+    pub fn encaps(&self) -> (SharedKey, DynCiphertext) {
+        self.encaps_with_rng(&mut OsRng)
+    }
+
+    /// Same as [DynEncapsulationKey::encaps], with the randomness drawn from **`rng`**, see [encaps_with_rng]
+    pub fn encaps_with_rng(&self, rng: &mut impl CryptoRngCore) -> (SharedKey, DynCiphertext) {
+        match self {
+            DynEncapsulationKey::MlKem512(ek) => {
+                let (key, c) = encaps_with_rng::<MlKem512>(ek.clone(), rng);
+                (key, DynCiphertext::MlKem512(c))
+            },
+            DynEncapsulationKey::MlKem768(ek) => {
+                let (key, c) = encaps_with_rng::<MlKem768>(ek.clone(), rng);
+                (key, DynCiphertext::MlKem768(c))
+            },
+            DynEncapsulationKey::MlKem1024(ek) => {
+                let (key, c) = encaps_with_rng::<MlKem1024>(ek.clone(), rng);
+                (key, DynCiphertext::MlKem1024(c))
+            },
+        }
+    }
+
+    /// Serializes the key, prefixed with the identifier of its parameter set
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            DynEncapsulationKey::MlKem512(ek) => with_parameter_set(self.parameter_set(), ek),
+            DynEncapsulationKey::MlKem768(ek) => with_parameter_set(self.parameter_set(), ek),
+            DynEncapsulationKey::MlKem1024(ek) => with_parameter_set(self.parameter_set(), ek),
+        }
+    }
+
+    /// Deserializes a key written by [DynEncapsulationKey::to_bytes], returning an [Error] if the identifier is unknown or the key is not valid for its parameter set
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match split_parameter_set(bytes)? {
+            (MlKemParameterSet::MlKem512, rest) => deserialize(rest).map(DynEncapsulationKey::MlKem512),
+            (MlKemParameterSet::MlKem768, rest) => deserialize(rest).map(DynEncapsulationKey::MlKem768),
+            (MlKemParameterSet::MlKem1024, rest) => deserialize(rest).map(DynEncapsulationKey::MlKem1024),
+        }
+    }
+}
+
+/// A [DecapsulationKey] of a parameter set chosen at runtime
+#[derive(Clone)]
+pub enum DynDecapsulationKey {
+    MlKem512(DecapsulationKey<MlKem512>),
+    MlKem768(DecapsulationKey<MlKem768>),
+    MlKem1024(DecapsulationKey<MlKem1024>),
+}
+
+impl DynDecapsulationKey {
+    /// The parameter set of the key
+    pub fn parameter_set(&self) -> MlKemParameterSet {
+        match self {
+            DynDecapsulationKey::MlKem512(_) => MlKemParameterSet::MlKem512,
+            DynDecapsulationKey::MlKem768(_) => MlKemParameterSet::MlKem768,
+            DynDecapsulationKey::MlKem1024(_) => MlKemParameterSet::MlKem1024,
+        }
+    }
+
+    /// The encapsulation key this decapsulation key was generated with
+    pub fn encapsulation_key(&self) -> DynEncapsulationKey {
+        match self {
+            DynDecapsulationKey::MlKem512(dk) => DynEncapsulationKey::MlKem512(dk.encapsulation_key().clone()),
+            DynDecapsulationKey::MlKem768(dk) => DynEncapsulationKey::MlKem768(dk.encapsulation_key().clone()),
+            DynDecapsulationKey::MlKem1024(dk) => DynEncapsulationKey::MlKem1024(dk.encapsulation_key().clone()),
+        }
+    }
+
+    /// # ML-KEM.Decaps
+    ///
+    /// **Returns** the shared key (Party A's copy), or the implicit rejection key, see [decaps]. The cyphertext has to be of the same parameter set, otherwise `Err(Error::ParameterMismatch)` is returned
+    pub fn decaps(&self, c: &DynCiphertext) -> Result<SharedKey, Error> {
+        match (self, c) {
+            (DynDecapsulationKey::MlKem512(dk), DynCiphertext::MlKem512(c)) => Ok(decaps_ref::<MlKem512>(c, dk)),
+            (DynDecapsulationKey::MlKem768(dk), DynCiphertext::MlKem768(c)) => Ok(decaps_ref::<MlKem768>(c, dk)),
+            (DynDecapsulationKey::MlKem1024(dk), DynCiphertext::MlKem1024(c)) => Ok(decaps_ref::<MlKem1024>(c, dk)),
+            _ => Err(Error::ParameterMismatch { expected: self.parameter_set().k(), found: c.parameter_set().k() })
+        }
+    }
+
+    /// Serializes the key, prefixed with the identifier of its parameter set
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            DynDecapsulationKey::MlKem512(dk) => with_parameter_set(self.parameter_set(), dk),
+            DynDecapsulationKey::MlKem768(dk) => with_parameter_set(self.parameter_set(), dk),
+            DynDecapsulationKey::MlKem1024(dk) => with_parameter_set(self.parameter_set(), dk),
+        }
+    }
+
+    /// Deserializes a key written by [DynDecapsulationKey::to_bytes], returning an [Error] if the identifier is unknown or the key is not valid for its parameter set, including the hash check of [check_decapsulation_key]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match split_parameter_set(bytes)? {
+            (MlKemParameterSet::MlKem512, rest) => load_decapsulation_key_bytes(rest).map(DynDecapsulationKey::MlKem512),
+            (MlKemParameterSet::MlKem768, rest) => load_decapsulation_key_bytes(rest).map(DynDecapsulationKey::MlKem768),
+            (MlKemParameterSet::MlKem1024, rest) => load_decapsulation_key_bytes(rest).map(DynDecapsulationKey::MlKem1024),
+        }
+    }
+}
+
+/// A [Ciphertext] of a parameter set chosen at runtime
+#[derive(Clone, PartialEq)]
+pub enum DynCiphertext {
+    MlKem512(Ciphertext<MlKem512>),
+    MlKem768(Ciphertext<MlKem768>),
+    MlKem1024(Ciphertext<MlKem1024>),
+}
+
+impl DynCiphertext {
+    /// The parameter set of the cyphertext
+    pub fn parameter_set(&self) -> MlKemParameterSet {
+        match self {
+            DynCiphertext::MlKem512(_) => MlKemParameterSet::MlKem512,
+            DynCiphertext::MlKem768(_) => MlKemParameterSet::MlKem768,
+            DynCiphertext::MlKem1024(_) => MlKemParameterSet::MlKem1024,
+        }
+    }
+
+    /// Serializes the cyphertext, prefixed with the identifier of its parameter set
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            DynCiphertext::MlKem512(c) => with_parameter_set(self.parameter_set(), c),
+            DynCiphertext::MlKem768(c) => with_parameter_set(self.parameter_set(), c),
+            DynCiphertext::MlKem1024(c) => with_parameter_set(self.parameter_set(), c),
+        }
+    }
+
+    /// Deserializes a cyphertext written by [DynCiphertext::to_bytes], returning an [Error] if the identifier is unknown or the length is wrong for its parameter set
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match split_parameter_set(bytes)? {
+            (MlKemParameterSet::MlKem512, rest) => deserialize(rest).map(DynCiphertext::MlKem512),
+            (MlKemParameterSet::MlKem768, rest) => deserialize(rest).map(DynCiphertext::MlKem768),
+            (MlKemParameterSet::MlKem1024, rest) => deserialize(rest).map(DynCiphertext::MlKem1024),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMETER_SETS: [MlKemParameterSet; 3] = [MlKemParameterSet::MlKem512, MlKemParameterSet::MlKem768, MlKemParameterSet::MlKem1024];
+
+    #[test]
+    fn test_dyn_mlkem() {
+        for parameter_set in PARAMETER_SETS {
+            // Party A
+            let (ek, dk) = parameter_set.key_gen();
+            let ek_bytes = ek.to_bytes();
+
+            // Party B, only learns the parameter set from the key it receives
+            let ek = DynEncapsulationKey::from_bytes(&ek_bytes).unwrap();
+            assert_eq!(ek.parameter_set(), parameter_set);
+
+            let (key, c) = ek.encaps();
+            let c_bytes = c.to_bytes();
+
+            // Party A
+            let c = DynCiphertext::from_bytes(&c_bytes).unwrap();
+            let dk = DynDecapsulationKey::from_bytes(&dk.to_bytes()).unwrap();
+
+            assert!(dk.encapsulation_key() == ek);
+            assert_eq!(dk.decaps(&c), Ok(key));
+        }
+    }
+
+    #[test]
+    fn test_dyn_bytes() {
+        let (ek, dk) = MlKemParameterSet::MlKem768.key_gen();
+        let (_key, c) = ek.encaps();
+
+        // The identifier, then the same bytes as the generic types
+        let DynEncapsulationKey::MlKem768(typed_ek) = &ek else { panic!("Wrong parameter set") };
+        assert_eq!(ek.to_bytes()[0], 2);
+        assert_eq!(&ek.to_bytes()[1..], typed_ek.to_bytes().as_slice());
+        assert_eq!(dk.to_bytes().len(), 1 + MlKem768::DECAPSULATION_KEY_SIZE);
+        assert_eq!(c.to_bytes().len(), 1 + MlKem768::CYPHERTEXT_SIZE);
+
+        assert_eq!(MlKemParameterSet::try_from(0).err(), Some(Error::UnknownParameterSet { found: 0 }));
+        assert_eq!(DynCiphertext::from_bytes(&[]).err(), Some(Error::InvalidLength { expected: 1, found: 0 }));
+
+        // The identifier and the length have to agree
+        let mut mislabeled = ek.to_bytes();
+        mislabeled[0] = u8::from(MlKemParameterSet::MlKem1024);
+        assert_eq!(DynEncapsulationKey::from_bytes(&mislabeled).err(), Some(Error::ParameterMismatch { expected: 4, found: 3 }));
+    }
+
+    #[test]
+    fn test_dyn_decapsulation_key_check() {
+        for parameter_set in PARAMETER_SETS {
+            let (_ek, dk) = parameter_set.key_gen();
+            let mut dk_bytes = dk.to_bytes();
+            assert!(DynDecapsulationKey::from_bytes(&dk_bytes).is_ok());
+
+            // Flip a bit of the stored H(ek), which sits after the identifier, dk_pke and ek
+            dk_bytes[1 + 768 * parameter_set.k() + 32] ^= 1;
+            assert_eq!(DynDecapsulationKey::from_bytes(&dk_bytes).err(), Some(Error::DecapsulationKeyHash));
+        }
+    }
+
+    #[test]
+    fn test_dyn_mismatch() {
+        let (_ek, dk) = MlKemParameterSet::MlKem512.key_gen();
+        let (ek, _dk) = MlKemParameterSet::MlKem1024.key_gen();
+        let (_key, c) = ek.encaps();
+
+        assert_eq!(dk.decaps(&c).err(), Some(Error::ParameterMismatch { expected: 2, found: 4 }));
+    }
+}
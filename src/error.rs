@@ -0,0 +1,40 @@
+//! Errors returned by deserialization and the validating parts of the API
+use std::fmt;
+
+/// Reasons an ML-KEM input can be rejected
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// The encapsulation key failed the FIPS 203 modulus check, a coefficient of `t` was not reduced modulo Q
+    EncapsulationKeyModulus,
+    /// The input did not have the length (in bytes) the parameter set requires
+    InvalidLength { expected: usize, found: usize },
+    /// The input was a bit vector that doesn't hold a whole number of bytes, `found` is its length in bits
+    InvalidBitLength { found: usize },
+    /// The hash stored in the decapsulation key does not match H(ek) of its encapsulation key
+    DecapsulationKeyHash,
+    /// The input has the length of a different parameter set, `expected` and `found` are the K of each
+    ParameterMismatch { expected: usize, found: usize },
+    /// The input has the right length, but decodes to values outside of their valid range
+    InvalidEncoding,
+    /// The identifier byte of a serialized `Dyn` value does not name a parameter set
+    UnknownParameterSet { found: u8 },
+    /// The pairwise consistency test failed, Encaps with the encapsulation key and Decaps with the decapsulation key gave different shared keys
+    PairwiseConsistency,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EncapsulationKeyModulus => write!(f, "encapsulation key coefficients are not reduced modulo {}", crate::params::Q),
+            Error::InvalidLength { expected, found } => write!(f, "expected {} bytes, found {}", expected, found),
+            Error::InvalidBitLength { found } => write!(f, "expected a whole number of bytes, found {} bits", found),
+            Error::DecapsulationKeyHash => write!(f, "decapsulation key hash does not match its encapsulation key"),
+            Error::ParameterMismatch { expected, found } => write!(f, "expected input for the parameter set with K = {}, found K = {}", expected, found),
+            Error::InvalidEncoding => write!(f, "input is not a valid encoding"),
+            Error::UnknownParameterSet { found } => write!(f, "unknown parameter set identifier {}", found),
+            Error::PairwiseConsistency => write!(f, "encapsulation and decapsulation keys do not form a key pair"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
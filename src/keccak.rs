@@ -0,0 +1,123 @@
+//! Keccak-f[1600] on 4 states at once, so 4 entries of the matrix A can be expanded in parallel
+//!
+//! Lane (x, y) of each state sits at index x + 5y, and holds the 4 states' lanes side by side, which is the layout a 256 bit
+//! vector wants. The AVX2 version in `avx2.rs` mirrors the portable one step for step.
+#[cfg(target_arch = "x86_64")]
+use crate::avx2;
+
+pub type StateX4 = [[u64; 4]; 25];
+
+pub const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+    0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008
+];
+
+// Rotation offsets of the rho step, for lane x + 5y
+pub const RHO_OFFSETS: [u32; 25] = [
+    0, 1, 62, 28, 27,
+    36, 44, 6, 55, 20,
+    3, 10, 43, 25, 39,
+    41, 45, 15, 21, 8,
+    18, 2, 61, 56, 14
+];
+
+// Where the pi step moves lane x + 5y to, (x, y) -> (y, 2x + 3y)
+pub const PI_DESTINATIONS: [usize; 25] = {
+    let mut destinations = [0; 25];
+    let mut i = 0;
+    while i < 25 {
+        let (x, y) = (i % 5, i / 5);
+        destinations[i] = y + 5 * ((2 * x + 3 * y) % 5);
+        i += 1;
+    }
+    destinations
+};
+
+pub fn keccak_f1600_x4(state: &mut StateX4) {
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: AVX2 support was just checked
+        return unsafe { avx2::keccak_f1600_x4(state) };
+    }
+
+    keccak_f1600_x4_portable(state)
+}
+
+fn map<F: Fn(u64, u64) -> u64>(a: [u64; 4], b: [u64; 4], f: F) -> [u64; 4] {
+    core::array::from_fn(|l| f(a[l], b[l]))
+}
+
+pub fn keccak_f1600_x4_portable(state: &mut StateX4) {
+    for round_constant in ROUND_CONSTANTS {
+        // Theta, every lane is xored with the parities of two neighbouring columns
+        let c: [[u64; 4]; 5] = core::array::from_fn(|x| {
+            (1..5).fold(state[x], |parity, y| map(parity, state[x + 5 * y], |p, s| p ^ s))
+        });
+        for x in 0..5 {
+            let d = map(c[(x + 4) % 5], c[(x + 1) % 5], |a, b| a ^ b.rotate_left(1));
+            for y in 0..5 {
+                state[x + 5 * y] = map(state[x + 5 * y], d, |s, d| s ^ d);
+            }
+        }
+
+        // Rho and pi, rotate every lane and move it
+        let mut b = [[0u64; 4]; 25];
+        for i in 0..25 {
+            b[PI_DESTINATIONS[i]] = state[i].map(|lane| lane.rotate_left(RHO_OFFSETS[i]));
+        }
+
+        // Chi, the only non-linear step, along each row
+        for y in 0..5 {
+            for x in 0..5 {
+                let not_and = map(b[(x + 1) % 5 + 5 * y], b[(x + 2) % 5 + 5 * y], |a, b| !a & b);
+                state[x + 5 * y] = map(b[x + 5 * y], not_and, |b, n| b ^ n);
+            }
+        }
+
+        // Iota
+        state[0] = state[0].map(|lane| lane ^ round_constant);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Keccak-f[1600] of the all zero state, from the Keccak team's KeccakF-1600-IntermediateValues.txt
+    const ZERO_PERMUTED: [u64; 5] = [0xF1258F7940E1DDE7, 0x84D5CCF933C0478A, 0xD598261EA65AA9EE, 0xBD1547306F80494D, 0x8B284E056253D057];
+
+    #[test]
+    fn test_keccak_f1600_x4() {
+        let mut state = [[0u64; 4]; 25];
+        keccak_f1600_x4_portable(&mut state);
+
+        for (lane, expected) in state.iter().zip(ZERO_PERMUTED) {
+            assert_eq!(*lane, [expected; 4]);
+        }
+    }
+
+    #[test]
+    fn test_xof_x4() {
+        use crate::crypt::*;
+
+        let rho: [u8; 32] = core::array::from_fn(|i| (7 * i + 3) as u8);
+        let indices = [(0, 0), (1, 0), (0, 1), (2, 3)];
+
+        let mut xof_x4 = XOFx4::new(&rho, indices);
+        let mut xofs = indices.map(|(i, j)| XOF::new(&rho, i, j));
+
+        // Several blocks, as SampleNTT sometimes needs more than one
+        for _ in 0..4 {
+            let blocks = xof_x4.read_blocks();
+            for (block, xof) in blocks.iter().zip(xofs.iter_mut()) {
+                let mut expected = [0u8; XOF_BLOCK];
+                xof.read_block(&mut expected);
+                assert_eq!(*block, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+// Implementations of the RustCrypto `kem` crate's traits, enabled by the `kem` feature
+//
+// EncapsulationKey implements `Encapsulate<Ciphertext<PARAMS>, SharedKey>` and DecapsulationKey implements `Decapsulate<Ciphertext<PARAMS>, SharedKey>`, for every parameter set
+use core::convert::Infallible;
+
+use kem::{Decapsulate, Encapsulate};
+use rand_core::CryptoRngCore;
+
+use crate::mlkem::*;
+use crate::params::*;
+
+impl<PARAMS: MlKemParams> Encapsulate<Ciphertext<PARAMS>, SharedKey> for EncapsulationKey<PARAMS> {
+    // Encaps can't fail once the key has been deserialized
+    type Error = Infallible;
+
+    fn encapsulate(&self, rng: &mut impl CryptoRngCore) -> Result<(Ciphertext<PARAMS>, SharedKey), Self::Error> {
+        let (key, c) = encaps_with_rng::<PARAMS>(self.clone(), rng);
+        Ok((c, key))
+    }
+}
+
+impl<PARAMS: MlKemParams> Decapsulate<Ciphertext<PARAMS>, SharedKey> for DecapsulationKey<PARAMS> {
+    // A bad cyphertext gives the implicit rejection key rather than an error
+    type Error = Infallible;
+
+    fn decapsulate(&self, encapsulated_key: &Ciphertext<PARAMS>) -> Result<SharedKey, Self::Error> {
+        Ok(decaps_ref::<PARAMS>(encapsulated_key, self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    // Written only against the kem traits, like protocol code would be
+    fn exchange<EK, DK, C, SS>(ek: &EK, dk: &DK) -> (SS, SS) where
+        EK: Encapsulate<C, SS>,
+        DK: Decapsulate<C, SS>,
+    {
+        let (c, key) = ek.encapsulate(&mut OsRng).unwrap();
+        let key_prime = dk.decapsulate(&c).unwrap();
+        (key, key_prime)
+    }
+
+    fn check_kem_traits<PARAMS: MlKemParams>() {
+        let (ek, dk) = key_gen::<PARAMS>();
+
+        let (key, key_prime) = exchange(&ek, &dk);
+        assert_eq!(key, key_prime);
+
+        // The free functions agree with the traits
+        let (c, key) = ek.encapsulate(&mut OsRng).unwrap();
+        assert_eq!(decaps::<PARAMS>(c, dk), key);
+    }
+
+    #[test]
+    fn test_kem_traits() {
+        check_kem_traits::<MlKem512>();
+        check_kem_traits::<MlKem768>();
+        check_kem_traits::<MlKem1024>();
+    }
+}
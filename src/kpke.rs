@@ -1,59 +1,48 @@
 
 use crate::crypt;
 use crate::params::*;
+use crate::variant::Variant;
 use crate::ring::*;
 use crate::sample;
+use crate::secret::Secret;
 
-pub type KpkeEncryptionKey <const K: usize> = (Vector<{K}>, [u8; 32]);
-pub type KpkeDecryptionKey <const K: usize> = Vector<{K}>;
+pub type KpkeEncryptionKey = (Vector, [u8; 32]);
+pub type KpkeDecryptionKey = Vector;
 
-pub type KpkeKeyGenOutput <const K: usize> = (KpkeEncryptionKey<{K}>, KpkeDecryptionKey<{K}>);
+pub type KpkeKeyGenOutput = (KpkeEncryptionKey, KpkeDecryptionKey);
 
-pub fn key_gen<PARAMS: MlKemParams>() -> KpkeKeyGenOutput<{PARAMS::K}> where
-    [(); 384 * PARAMS::K + 32]: ,
-    [(); 768 * PARAMS::K + 96]: ,
-    [(); PARAMS::K]: ,
-    [(); PARAMS::ETA_2]: ,
-    [(); 64 * PARAMS::ETA_1]: ,
-    [(); 32 * (PARAMS::D_U * PARAMS::K + PARAMS::D_V)]: ,
-{
-    // This is the main source of randomness for Party A (he also would've generated a value to use a random implict rejection answer).
-    let d = crypt::random_bytes::<32>();
-
-    let (rho, sigma) = crypt::g::<32>(&d);
+pub fn key_gen<PARAMS: MlKemParams>(d: [u8; 32]) -> KpkeKeyGenOutput {
+    // d is the main source of randomness for Party A (they also would've generated a value to use a random implict rejection answer).
+    // In the final FIPS 203 it is domain separated with the parameter set's K before hashing, so a seed never produces related keys across parameter sets
+    let (rho, sigma) = PARAMS::Variant::key_gen_seed(&d, PARAMS::K as u8);
+    let sigma = Secret::new(sigma);
 
     let mut n = 0;
 
     // Our public key, (the bad bases)
-    let mut a: Matrix<{PARAMS::K}> = Matrix::new_ntt();
-
-    for i in 0..PARAMS::K {
-        for j in 0..PARAMS::K {
-            a.data[i][j] = sample::sample_ntt(crypt::XOF::new(&rho, i as u8, j as u8)) // XOF stream is instantied here for each index of the matrix
-        }
-    }
+    let a = expand_a::<PARAMS>(&rho);
 
     // Our secret key
-    let mut s = Vector::new_degree255();
+    let mut s = Vector::new_degree255(PARAMS::K);
     for i in 0..PARAMS::K {
-        s.data[i] = sample::sample_poly_cbd::<{PARAMS::ETA_1}>(
-            crypt::prf::<{PARAMS::ETA_1}>(&sigma, n)
+        s.data[i] = sample::sample_poly_cbd(
+            crypt::prf(&sigma, n, PARAMS::ETA_1), PARAMS::ETA_1
         );
         n += 1;
     }
 
     // Our error vector
-    let mut e = Vector::new_degree255();
+    let mut e = Secret::new(Vector::new_degree255(PARAMS::K));
     for i in 0..PARAMS::K {
-        e.data[i] = sample::sample_poly_cbd::<{PARAMS::ETA_1}>(
-            crypt::prf::<{PARAMS::ETA_1}>(&sigma, n)
+        e.data[i] = sample::sample_poly_cbd(
+            crypt::prf(&sigma, n, PARAMS::ETA_1), PARAMS::ETA_1
         );
         n += 1;
     }    
     
     // NTT both
     let s = s.ntt();
-    let e = e.ntt();
+    e.ntt_in_place();
 
     // The encapsulation key we send includes this vector t, our secret linear transformation, with some rror
     let mut t = a.right_vector_multiply(&s);
@@ -64,77 +53,92 @@ pub fn key_gen<PARAMS: MlKemParams>() -> KpkeKeyGenOutput<{PARAMS::K}> where
     ((t, rho), s)
 }
 
-pub type Cyphertext<const K: usize, const D_U: usize, const D_V: usize> = (Compressed<{D_U}, Vector<{K}>>, Compressed<{D_V}, Ring>);
+// Expands the seed rho into the matrix A, KeyGen and every encryption to the same key recreate the same matrix
+pub fn expand_a<PARAMS: MlKemParams>(rho: &[u8; 32]) -> Matrix {
+    let mut a = Matrix::new_ntt(PARAMS::K);
 
-pub fn encrypt<PARAMS: MlKemParams>(ek_pke: KpkeEncryptionKey<{PARAMS::K}>, m: Compressed<1,Ring>, rand: [u8; 32]) -> Cyphertext<{PARAMS::K}, {PARAMS::D_U}, {PARAMS::D_V}> where
-    [(); PARAMS::K]: ,
-    [(); 64 * PARAMS::ETA_1]: ,
-    [(); 64 * PARAMS::ETA_2]: ,
-    [(); 384 * PARAMS::K + 32]: ,
-    [(); PARAMS::D_U]: ,
-{
-    let mut n = 0;
-
-    let (t, rho) = ek_pke; // rho is the seed for A, the matrix, t comes from KeyGen's computation with their secret
+    let entries: Vec<(usize, usize)> = (0..PARAMS::K).flat_map(|i| (0..PARAMS::K).map(move |j| (i, j))).collect();
 
-    // Recreate the matrix A
-    let mut a: Matrix<{PARAMS::K}> = Matrix::new_ntt();
-    for i in 0..PARAMS::K {
-        for j in 0..PARAMS::K {
-            a.data[i][j] = sample::sample_ntt(crypt::XOF::new(&rho, i as u8, j as u8));
+    // Entries are expanded 4 at a time, which covers K = 2 and K = 4 and leaves one entry of K = 3 for a single XOF
+    let mut batches = entries.chunks_exact(4);
+    for batch in &mut batches {
+        let xof_streams = crypt::XOFx4::new(rho, core::array::from_fn(|n| (batch[n].1 as u8, batch[n].0 as u8))); // Note the j, i ordering
+        for (&(i, j), ring) in batch.iter().zip(sample::sample_ntt_x4(xof_streams)) {
+            a.data[i][j] = ring;
         }
     }
 
+    for &(i, j) in batches.remainder() {
+        a.data[i][j] = sample::sample_ntt(crypt::XOF::new(rho, j as u8, i as u8)) // XOF stream is instantied here for each index of the matrix, note the j, i ordering
+    }
+    a
+}
+
+pub type Cyphertext = (Compressed<Vector>, Compressed<Ring>);
+
+// The matrix A is expanded from the key's rho by the caller, so it can be reused across encryptions to the same key
+// m is borrowed so the caller's wrapped copy is the only one, and every intermediate that depends on r or m is wiped
+pub fn encrypt<PARAMS: MlKemParams>(a: &Matrix, ek_pke: &KpkeEncryptionKey, m: &Compressed<Ring>, rand: [u8; 32]) -> Cyphertext {
+    let mut n = 0;
+
+    let (t, _) = ek_pke; // t comes from KeyGen's computation with their secret
+
+    let rand = Secret::new(rand);
+
     // Encrpytor's Secret (Equivalent of S in key_gen)
-    let mut r: Vector<{PARAMS::K}> = Vector::new_degree255();
+    let mut r = Secret::new(Vector::new_degree255(PARAMS::K));
     for i in 0..PARAMS::K {
-        r.data[i] = sample::sample_poly_cbd::<{PARAMS::ETA_1}>(
-            crypt::prf::<{PARAMS::ETA_1}>(&rand, n as u8)
+        r.data[i] = sample::sample_poly_cbd(
+            crypt::prf(&rand, n as u8, PARAMS::ETA_1), PARAMS::ETA_1
         );
         n += 1;
     }
 
     // Error vector to be added to R^T * A
-    let mut e_1: Vector<{PARAMS::K}> = Vector::new_degree255();
+    let mut e_1 = Secret::new(Vector::new_degree255(PARAMS::K));
     for i in 0..PARAMS::K {
-        e_1.data[i] = sample::sample_poly_cbd::<{PARAMS::ETA_2}>(
-            crypt::prf::<{PARAMS::ETA_2}>(&rand, n as u8)
+        e_1.data[i] = sample::sample_poly_cbd(
+            crypt::prf(&rand, n as u8, PARAMS::ETA_2), PARAMS::ETA_2
         );
         n += 1;
     }
 
     // Error vector to be added to the shared key V (R^T * t) 
-    let e_2 = sample::sample_poly_cbd::<{PARAMS::ETA_2}>(
-        crypt::prf::<{PARAMS::ETA_2}>(&rand, n as u8)
-    );
+    let e_2 = Secret::new(sample::sample_poly_cbd(
+        crypt::prf(&rand, n as u8, PARAMS::ETA_2), PARAMS::ETA_2
+    ));
 
-    let r = r.ntt();
+    r.ntt_in_place();
 
     // u is the encryptors computation with A and their secret, but this one is left-multiplied
-    let mut u = a.left_vector_multiply(&r).inverse_ntt();
+    let mut u = Secret::new(a.left_vector_multiply(&r).inverse_ntt());
     u.add(&e_1);
-    let u_compressed = Compressed::<{PARAMS::D_U}, Vector<{PARAMS::K}>>::compress(u);
+    let u_compressed = Compressed::<Vector>::compress((*u).clone(), PARAMS::D_U);
 
-    let m = m.decompress();
+    let m = Secret::new(m.clone().decompress());
 
     // v is our shared secret, notice for both parties its approximately rAs.
-    let mut v_ntt = r.inner_product(t);
-    v_ntt.inverse_ntt().add(&e_2).add(&m);
+    let mut v = Secret::new(t.inner_product(&r));
+    v.inverse_ntt().add(&e_2).add(&m);
 
-    let v_compressed = Compressed::<{PARAMS::D_V}, Ring>::compress(v_ntt);
+    let v_compressed = Compressed::<Ring>::compress((*v).clone(), PARAMS::D_V);
 
     (u_compressed, v_compressed)
 }
 
-pub fn decrypt<PARAMS: MlKemParams>(dk_kpe: Vector<{PARAMS::K}>, c: Cyphertext<{PARAMS::K}, {PARAMS::D_U}, {PARAMS::D_V}>) -> Compressed<1, Ring> {
+pub fn decrypt<PARAMS: MlKemParams>(dk_kpe: &Vector, c: Cyphertext) -> Compressed<Ring> {
+    debug_assert_eq!((c.0.d, c.1.d), (PARAMS::D_U, PARAMS::D_V));
+
     //Decompress Cyphertext
     let u_compressed = c.0; // rA + e from the encryptor
     let v_compressed = c.1; // rt + e + m from the encryptor
 
-    let u = u_compressed.decompress();
+    let u = u_compressed.decompress().ntt();
 
-    let mut v = v_compressed.decompress();
-    v.sub(&dk_kpe.inner_product(u.ntt()).inverse_ntt());
+    let mut v = Secret::new(v_compressed.decompress());
+    let mut w = Secret::new(u.inner_product(dk_kpe)); // s^T * u, removes the masking from v
+    w.inverse_ntt();
+    v.sub(&w);
 
-    Compressed::<1, Ring>::compress(v)
+    Compressed::<Ring>::compress((*v).clone(), 1)
 }
\ No newline at end of file
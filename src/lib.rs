@@ -1,17 +1,41 @@
 //! Find more in the repo's or crate's README.md
-#![allow(incomplete_features)]
 
-#![feature(generic_const_exprs)]
+// Names follow FIPS 203 (NTT, XOF), and parameter sets are passed around as PARAMS
+#![allow(clippy::upper_case_acronyms)]
 
 mod crypt;
+mod keccak;
+mod error;
+// Only used to check the precomputed zeta tables
+#[cfg(test)]
 mod util;
+mod secret;
 pub mod params;
+pub mod variant;
 mod ring;
 mod sample;
+#[cfg(target_arch = "x86_64")]
+mod avx2;
 pub mod serialize;
 mod kpke;
 
 #[cfg(test)]
 mod seeded_test;
+#[cfg(test)]
+mod rejection_test;
+#[cfg(all(test, target_arch = "x86_64"))]
+mod avx2_test;
+
+pub mod mlkem;
+pub mod dynamic;
+
+#[cfg(feature = "kem")]
+mod kem_traits;
+
+#[cfg(feature = "bench-internals")]
+#[doc(hidden)]
+pub mod internals {
+    pub use crate::ring::{Ring, RingRepresentation};
+}
 
-pub mod mlkem;
\ No newline at end of file
+pub use error::Error;
\ No newline at end of file
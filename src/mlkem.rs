@@ -1,7 +1,9 @@
 //! The main API functions which implement ML-KEM.KeyGen, ML-KEM.Encaps, and ML-KEM.Decaps
 
 
-use bitvec::view::BitView;
+use bitvec::vec::BitVec;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use rand_core::{CryptoRngCore, OsRng};
 
 use crate::kpke;
 use kpke::{
@@ -11,122 +13,589 @@ use kpke::{
 };
 
 use crate::params::*;
+use crate::variant::Variant;
 use crate::crypt;
 use crate::ring::*;
 use crate::serialize::*;
+use crate::error::Error;
+use crate::secret::{Secret, Wipe};
+
+use std::fmt;
+use std::marker::PhantomData;
+
+pub(crate) type MlKemEncapsulationKey = KpkeEncryptionKey;
+pub(crate) type MlKemDecapsulationKey = (KpkeDecryptionKey, KpkeEncryptionKey, [u8; 32], [u8; 32]);
+pub(crate) type MlKemCyphertext = kpke::Cyphertext;
 
 /// Encapsulation Key generated by ML-KEM.KeyGen
 /// 
-/// Expects a const generic parameter to be provided, **`K`**, which should come from the respect value in the MlKemParams trait: `PARAMS::K` where PARAMS is whatever ML-KEM parameter set you are using (or its generic)
-pub type MlKemEncapsulationKey<const K: usize> = KpkeEncryptionKey<K>;
+/// Expects a generic parameter to be provided, **`PARAMS`**, the ML-KEM parameters which should implement the trait [MlKemParams], keys of different parameter sets (or variants) can't be mixed up.
+/// 
+/// Its contents are only reachable through the accessors below and the serialization traits in [crate::serialize]
+#[repr(transparent)] // So a decapsulation key can lend out the encapsulation key it holds, see from_inner
+pub struct EncapsulationKey<PARAMS: MlKemParams> {
+    pub(crate) inner: MlKemEncapsulationKey,
+    params: PhantomData<PARAMS>
+}
+
+impl<PARAMS: MlKemParams> EncapsulationKey<PARAMS> {
+    pub(crate) fn new(inner: MlKemEncapsulationKey) -> Self {
+        debug_assert_eq!(inner.0.data.len(), PARAMS::K);
+        EncapsulationKey { inner, params: PhantomData }
+    }
+
+    // Borrows an inner representation as an encapsulation key, without copying it
+    fn from_inner(inner: &MlKemEncapsulationKey) -> &Self {
+        debug_assert_eq!(inner.0.data.len(), PARAMS::K);
+        // SAFETY: EncapsulationKey is repr(transparent) over MlKemEncapsulationKey, PhantomData is zero sized
+        unsafe { &*(inner as *const MlKemEncapsulationKey as *const Self) }
+    }
+
+    /// The 32 byte seed the matrix A is generated from
+    pub fn rho(&self) -> &[u8; 32] {
+        &self.inner.1
+    }
+
+    /// H(ek), the SHA3-256 hash of the serialized encapsulation key
+    pub fn hash(&self) -> [u8; 32] {
+        crypt::h(&self.inner.serialize().into_vec())
+    }
+
+    /// Expands the matrix A and hashes the key once, for a key that will be encapsulated to many times, see [PreparedEncapsulationKey]
+    pub fn prepare(&self) -> PreparedEncapsulationKey<PARAMS> {
+        PreparedEncapsulationKey {
+            a: kpke::expand_a::<PARAMS>(self.rho()),
+            hash: self.hash(),
+            ek: self.clone()
+        }
+    }
+}
+
+impl<PARAMS: MlKemParams> Clone for EncapsulationKey<PARAMS> {
+    fn clone(&self) -> Self {
+        EncapsulationKey::new(self.inner.clone())
+    }
+}
+
+impl<PARAMS: MlKemParams> PartialEq for EncapsulationKey<PARAMS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
 
 /// Decapsulation Key generated by ML-KEM.KeyGen
 /// 
-/// Expects a const generic parameter to be provided, **`K`**, which should come from the respect value in the MlKemParams trait: `PARAMS::K` where PARAMS is whatever ML-KEM parameter set you are using (or its generic)
-pub type MlKemDecapsulationKey<const K: usize> = (KpkeDecryptionKey<K>, KpkeEncryptionKey<K>, [u8; 32], [u8; 32]);
+/// Expects a generic parameter to be provided, **`PARAMS`**, the ML-KEM parameters which should implement the trait [MlKemParams]
+/// 
+/// Holds the secret decryption key, so its contents are only reachable through the accessors below and the serialization traits in [crate::serialize]
+pub struct DecapsulationKey<PARAMS: MlKemParams> {
+    pub(crate) inner: MlKemDecapsulationKey,
+    params: PhantomData<PARAMS>
+}
+
+impl<PARAMS: MlKemParams> DecapsulationKey<PARAMS> {
+    pub(crate) fn new(inner: MlKemDecapsulationKey) -> Self {
+        debug_assert_eq!(inner.0.data.len(), PARAMS::K);
+        DecapsulationKey { inner, params: PhantomData }
+    }
+
+    /// The encapsulation key this decapsulation key was generated with
+    pub fn encapsulation_key(&self) -> &EncapsulationKey<PARAMS> {
+        EncapsulationKey::from_inner(&self.inner.1)
+    }
+
+    /// The stored hash of the encapsulation key, H(ek)
+    pub fn hash(&self) -> &[u8; 32] {
+        &self.inner.2
+    }
+
+    /// Expands the matrix A once, for a key that will decapsulate many times, see [PreparedDecapsulationKey]
+    pub fn prepare(&self) -> PreparedDecapsulationKey<PARAMS> {
+        PreparedDecapsulationKey {
+            a: kpke::expand_a::<PARAMS>(self.encapsulation_key().rho()),
+            dk: self.clone()
+        }
+    }
+}
+
+impl<PARAMS: MlKemParams> Clone for DecapsulationKey<PARAMS> {
+    fn clone(&self) -> Self {
+        DecapsulationKey::new(self.inner.clone())
+    }
+}
+
+// The secret vector s and the implicit rejection seed z are wiped, the rest of the key is public
+impl<PARAMS: MlKemParams> Drop for DecapsulationKey<PARAMS> {
+    fn drop(&mut self) {
+        self.inner.0.wipe();
+        self.inner.3.wipe();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<PARAMS: MlKemParams> zeroize::ZeroizeOnDrop for DecapsulationKey<PARAMS> {}
+
+/// Shared key agreed on by ML-KEM.Encaps and ML-KEM.Decaps
+/// 
+/// Compares in constant time, and is wiped from memory when dropped (with the default `zeroize` feature)
+#[derive(Clone)]
+pub struct SharedKey([u8; 32]);
+
+impl SharedKey {
+    pub(crate) fn new(key: [u8; 32]) -> Self {
+        SharedKey(key)
+    }
+
+    /// The 32 bytes of the shared key
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl PartialEq for SharedKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl Eq for SharedKey {}
+
+// Keeps the key out of logs and panic messages
+impl fmt::Debug for SharedKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedKey(..)")
+    }
+}
+
+impl Drop for SharedKey {
+    fn drop(&mut self) {
+        self.0.wipe();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SharedKey {}
+
+/// The 64 byte seed `d || z` a key pair is generated from
+/// 
+/// FIPS 203 allows storing a decapsulation key as this seed instead of its expanded 768K+96 byte form, [DecapsulationKeySeed::expand] runs ML-KEM.KeyGen_internal to get the key pair back. The seed is not tied to a parameter set, the same seed expands to a different key pair for each one.
+/// 
+/// Wiped from memory when dropped (with the default `zeroize` feature)
+#[derive(Clone)]
+pub struct DecapsulationKeySeed {
+    d: [u8; 32],
+    z: [u8; 32]
+}
+
+impl DecapsulationKeySeed {
+    /// Length of the serialized seed in bytes
+    pub const SIZE: usize = 64;
+
+    /// Builds the seed from **`d`** (used to generate the K-PKE keys) and **`z`** (the implicit rejection randomness), see [key_gen_internal]
+    pub fn new(d: [u8; 32], z: [u8; 32]) -> Self {
+        DecapsulationKeySeed { d, z }
+    }
+
+    /// Draws a new seed from the operating system
+    pub fn generate() -> Self {
+        Self::generate_with_rng(&mut OsRng)
+    }
+
+    /// Draws a new seed from **`rng`**, in the same order as [key_gen_with_rng], so both give the same key pair for the same RNG output
+    pub fn generate_with_rng(rng: &mut impl CryptoRngCore) -> Self {
+        let d = crypt::random_bytes::<32>(rng);
+        let z = crypt::random_bytes::<32>(rng);
+
+        DecapsulationKeySeed { d, z }
+    }
+
+    /// The seed **`d`** the K-PKE keys are generated from
+    pub fn d(&self) -> &[u8; 32] {
+        &self.d
+    }
+
+    /// The implicit rejection seed **`z`**
+    pub fn z(&self) -> &[u8; 32] {
+        &self.z
+    }
+
+    /// # ML-KEM.KeyGen_internal
+    /// 
+    /// Expects a generic parameter to be provided, **`PARAMS`**, the ML-KEM parameters which should implement the trait [MlKemParams]
+    /// 
+    /// **Returns** a tuple containing the ML-KEM encapsulation key and the ML-KEM decapsulation key this seed expands to
+    pub fn expand<PARAMS: MlKemParams>(&self) -> (EncapsulationKey<PARAMS>, DecapsulationKey<PARAMS>) {
+        key_gen_internal::<PARAMS>(self.d, self.z)
+    }
+}
+
+impl PartialEq for DecapsulationKeySeed {
+    fn eq(&self, other: &Self) -> bool {
+        (self.d.ct_eq(&other.d) & self.z.ct_eq(&other.z)).into()
+    }
+}
+
+impl Eq for DecapsulationKeySeed {}
+
+// Keeps the seed out of logs and panic messages
+impl fmt::Debug for DecapsulationKeySeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DecapsulationKeySeed(..)")
+    }
+}
+
+impl Drop for DecapsulationKeySeed {
+    fn drop(&mut self) {
+        self.d.wipe();
+        self.z.wipe();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for DecapsulationKeySeed {}
+
+/// Cyphertext generated by ML-KEM.Encaps
+/// 
+/// Expects a generic parameter to be provided, **`PARAMS`**, the ML-KEM parameters which should implement the trait [MlKemParams]
+pub struct Ciphertext<PARAMS: MlKemParams> {
+    pub(crate) inner: MlKemCyphertext,
+    params: PhantomData<PARAMS>
+}
+
+impl<PARAMS: MlKemParams> Ciphertext<PARAMS> {
+    pub(crate) fn new(inner: MlKemCyphertext) -> Self {
+        debug_assert_eq!((inner.0.value.data.len(), inner.0.d, inner.1.d), (PARAMS::K, PARAMS::D_U, PARAMS::D_V));
+        Ciphertext { inner, params: PhantomData }
+    }
+}
+
+impl<PARAMS: MlKemParams> Clone for Ciphertext<PARAMS> {
+    fn clone(&self) -> Self {
+        Ciphertext::new(self.inner.clone())
+    }
+}
+
+impl<PARAMS: MlKemParams> PartialEq for Ciphertext<PARAMS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+/// Encapsulation key with the matrix A and H(ek) precomputed
+/// 
+/// Encaps otherwise expands A from rho (K² rejection samplings) and hashes the key on every call, preparing a long lived key once saves both. Made with [EncapsulationKey::prepare], encapsulates to the same shared keys and cyphertexts as the unprepared key.
+pub struct PreparedEncapsulationKey<PARAMS: MlKemParams> {
+    ek: EncapsulationKey<PARAMS>,
+    a: Matrix,
+    hash: [u8; 32]
+}
+
+impl<PARAMS: MlKemParams> PreparedEncapsulationKey<PARAMS> {
+    /// The encapsulation key this was prepared from
+    pub fn encapsulation_key(&self) -> &EncapsulationKey<PARAMS> {
+        &self.ek
+    }
+
+    /// H(ek), computed once when the key was prepared
+    pub fn hash(&self) -> &[u8; 32] {
+        &self.hash
+    }
+
+    /// # ML-KEM.Encaps
+    /// 
+    /// Same as [encaps], reusing the precomputed values
+    pub fn encaps(&self) -> (SharedKey, Ciphertext<PARAMS>) {
+        self.encaps_with_rng(&mut OsRng)
+    }
+
+    /// Same as [encaps_with_rng], reusing the precomputed values
+    pub fn encaps_with_rng(&self, rng: &mut impl CryptoRngCore) -> (SharedKey, Ciphertext<PARAMS>) {
+        let m = Secret::new(crypt::random_bytes::<32>(rng));
+
+        self.encaps_internal(*m)
+    }
+
+    /// Same as [encaps_internal], reusing the precomputed values
+    pub fn encaps_internal(&self, m: [u8; 32]) -> (SharedKey, Ciphertext<PARAMS>) {
+        encaps_with_a(&self.ek, &self.a, &self.hash, m)
+    }
+}
+
+impl<PARAMS: MlKemParams> Clone for PreparedEncapsulationKey<PARAMS> {
+    fn clone(&self) -> Self {
+        PreparedEncapsulationKey { ek: self.ek.clone(), a: self.a.clone(), hash: self.hash }
+    }
+}
+
+/// Decapsulation key with the matrix A precomputed
+/// 
+/// Decaps re-encrypts the decrypted message to check the cyphertext, which otherwise expands A from rho on every call. Made with [DecapsulationKey::prepare], H(ek) is already stored in the decapsulation key.
+pub struct PreparedDecapsulationKey<PARAMS: MlKemParams> {
+    dk: DecapsulationKey<PARAMS>,
+    a: Matrix
+}
+
+impl<PARAMS: MlKemParams> PreparedDecapsulationKey<PARAMS> {
+    /// The decapsulation key this was prepared from
+    pub fn decapsulation_key(&self) -> &DecapsulationKey<PARAMS> {
+        &self.dk
+    }
+
+    /// # ML-KEM.Decaps
+    /// 
+    /// Same as [decaps], reusing the precomputed matrix and without taking ownership of the cyphertext
+    pub fn decaps(&self, c: &Ciphertext<PARAMS>) -> SharedKey {
+        decaps_choice(c, &self.dk, &self.a).0
+    }
+}
+
+impl<PARAMS: MlKemParams> Clone for PreparedDecapsulationKey<PARAMS> {
+    fn clone(&self) -> Self {
+        PreparedDecapsulationKey { dk: self.dk.clone(), a: self.a.clone() }
+    }
+}
 
 /// # ML-KEM.KeyGen 
 /// 
 /// Expects a generic parameter to be provided, **`PARAMS`**, the ML-KEM parameters which should implement the trait [MlKemParams]
 /// 
+/// The randomness comes from the operating system, use [key_gen_with_rng] to provide your own
+/// 
 /// **Returns** a tuple containing the ML-KEM encapsulation key and the ML-KEM decapsulation key 
 /// 
-pub fn key_gen<PARAMS: MlKemParams> () -> (MlKemEncapsulationKey<{PARAMS::K}>, MlKemDecapsulationKey<{PARAMS::K}>) where
-    [(); 768 * PARAMS::K + 96]: ,
-    [(); PARAMS::ETA_1]: ,
-    [(); PARAMS::ETA_2]: ,
-    [(); 64 * PARAMS::ETA_1]: ,
-    [(); 384 * PARAMS::K + 32]: ,
-    [(); 32 * (PARAMS::D_U * PARAMS::K + PARAMS::D_V)]: ,
-{
-    let z = crypt::random_bytes::<32>();
+pub fn key_gen<PARAMS: MlKemParams> () -> (EncapsulationKey<PARAMS>, DecapsulationKey<PARAMS>) {
+    key_gen_with_rng::<PARAMS>(&mut OsRng)
+}
+
+/// # ML-KEM.KeyGen (with a caller provided RNG)
+/// 
+/// Same as [key_gen], but the seeds **`d`** and **`z`** are drawn from **`rng`**, which can be any cryptographically secure RNG implementing `rand_core`'s traits (e.g. one backed by an HSM, or a deterministic DRBG for testing)
+/// 
+/// **Returns** a tuple containing the ML-KEM encapsulation key and the ML-KEM decapsulation key 
+pub fn key_gen_with_rng<PARAMS: MlKemParams> (rng: &mut impl CryptoRngCore) -> (EncapsulationKey<PARAMS>, DecapsulationKey<PARAMS>) {
+    DecapsulationKeySeed::generate_with_rng(rng).expand::<PARAMS>()
+}
+
+/// # ML-KEM.KeyGen (with a pairwise consistency test)
+/// 
+/// Same as [key_gen], but the fresh key pair goes through the [check_key_pair] Encaps / Decaps round trip before being returned, as FIPS 140-3 requires of newly generated key pairs
+/// 
+/// **Returns** a tuple containing the ML-KEM encapsulation key and the ML-KEM decapsulation key, or `Err(Error::PairwiseConsistency)` if the check failed
+pub fn key_gen_checked<PARAMS: MlKemParams> () -> Result<(EncapsulationKey<PARAMS>, DecapsulationKey<PARAMS>), Error> {
+    key_gen_checked_with_rng::<PARAMS>(&mut OsRng)
+}
+
+/// # ML-KEM.KeyGen (with a pairwise consistency test and a caller provided RNG)
+/// 
+/// Same as [key_gen_checked], the seeds **`d`** and **`z`**, and then the message of the test's Encaps, are drawn from **`rng`**
+pub fn key_gen_checked_with_rng<PARAMS: MlKemParams> (rng: &mut impl CryptoRngCore) -> Result<(EncapsulationKey<PARAMS>, DecapsulationKey<PARAMS>), Error> {
+    let (ek, dk) = key_gen_with_rng::<PARAMS>(rng);
 
+    check_key_pair_with_rng(&ek, &dk, rng)?;
+
+    Ok((ek, dk))
+}
+
+/// # Pairwise Consistency Test
+/// 
+/// Encapsulates to **`ek_mlkem`** and decapsulates the cyphertext with **`dk_mlkem`**, both have to agree on the shared key. Useful for keys loaded from storage, [key_gen_checked] runs it on freshly generated ones.
+/// 
+/// **Returns** `Err(Error::PairwiseConsistency)` if the shared keys differ
+pub fn check_key_pair<PARAMS: MlKemParams>(ek_mlkem: &EncapsulationKey<PARAMS>, dk_mlkem: &DecapsulationKey<PARAMS>) -> Result<(), Error> {
+    check_key_pair_with_rng(ek_mlkem, dk_mlkem, &mut OsRng)
+}
+
+/// Same as [check_key_pair], with the message of the test's Encaps drawn from **`rng`**
+pub fn check_key_pair_with_rng<PARAMS: MlKemParams>(ek_mlkem: &EncapsulationKey<PARAMS>, dk_mlkem: &DecapsulationKey<PARAMS>, rng: &mut impl CryptoRngCore) -> Result<(), Error> {
+    let (key, c) = encaps_with_rng::<PARAMS>(ek_mlkem.clone(), rng);
+
+    match decaps_ref(&c, dk_mlkem) == key {
+        true => Ok(()),
+        false => Err(Error::PairwiseConsistency)
+    }
+}
+
+/// # ML-KEM.KeyGen_internal
+/// 
+/// Deterministic version of [key_gen], the randomness is instead provided by the caller. Useful for regenerating a key pair from stored seeds, or for running known-answer tests.
+/// 
+/// Takes in the 32 byte seed **`d`** (used to generate the K-PKE keys) and the 32 byte seed **`z`** (the implicit rejection randomness). These should come from a secure random source.
+/// 
+/// **Returns** a tuple containing the ML-KEM encapsulation key and the ML-KEM decapsulation key 
+pub fn key_gen_internal<PARAMS: MlKemParams> (d: [u8; 32], z: [u8; 32]) -> (EncapsulationKey<PARAMS>, DecapsulationKey<PARAMS>) {
     //Encryption key, Decryption key
-    let (ek, dk) : KpkeKeyGenOutput<{PARAMS::K}> = kpke::key_gen::<PARAMS>();
+    let (ek, dk) : KpkeKeyGenOutput = kpke::key_gen::<PARAMS>(d);
     
     // Encapsulation key is the encryption key
-    let encapsulation_key: MlKemEncapsulationKey<{PARAMS::K}> = ek;
+    let encapsulation_key: MlKemEncapsulationKey = ek;
 
     let hash = crypt::h(&encapsulation_key.serialize().into_vec());
 
     // Fujisaki-Okamoto transformation, turn decryption key into decapsulation
-    let decapsulation_key: MlKemDecapsulationKey<{PARAMS::K}> = (dk, encapsulation_key.clone(), hash, z);
+    let decapsulation_key: MlKemDecapsulationKey = (dk, encapsulation_key.clone(), hash, z);
     
-    (encapsulation_key, decapsulation_key)
+    (EncapsulationKey::new(encapsulation_key), DecapsulationKey::new(decapsulation_key))
 }
-/// Cyphertext generated by ML-KEM.Encaps
-/// 
-/// Expects 3 const generic parameters which determine the size of the ring vectors, and the compression factor for the 2 main cyphertext components. These parameters should coincide with their respective values in the MlKemParams trait: `PARAMS::K`, `PARAMS::D_U`, `PARAMS::D_V` where PARAMS is whatever ML-KEM parameter set you are using (or its generic)
-pub type MlKemCyphertext<const K: usize, const D_U: usize, const D_V: usize> = kpke::Cyphertext<K, D_U, D_V>;
-                                                                               //  Shared Key
 
 /// # ML-KEM.Encaps
 /// 
 /// Expects a generic parameter to be provided, **`PARAMS`**, the ML-KEM parameters which should implement the trait [MlKemParams]
 /// 
-/// Takes in the public encapsulation key as input, this ek_mlkem is expected to already be deserialized and match the type signature of [MlKemEncapsulationKey]
+/// Takes in the public encapsulation key as input, this ek_mlkem is expected to already be deserialized into an [EncapsulationKey]
+/// 
+/// The randomness comes from the operating system, use [encaps_with_rng] to provide your own
 /// 
 /// **Returns** a tuple containing the shared key (Party B's copy) and the cyphertext to be sent back
 /// 
-pub fn encaps<PARAMS: MlKemParams>(ek_mlkem: MlKemEncapsulationKey<{PARAMS::K}>) -> ([u8;32], MlKemCyphertext<{PARAMS::K}, {PARAMS::D_U}, {PARAMS::D_V}>) where
-    [(); 768 * PARAMS::K + 96]: ,
-    [(); PARAMS::K]: ,
-    [(); 384 * PARAMS::K + 32]: ,
-    [(); 32*(PARAMS::D_U * PARAMS::K + PARAMS::D_V)]: ,
-    [(); 64 * PARAMS::ETA_1]: ,
-    [(); 64 * PARAMS::ETA_2]: 
-{
-    let m = crypt::random_bytes::<32>();
+pub fn encaps<PARAMS: MlKemParams>(ek_mlkem: EncapsulationKey<PARAMS>) -> (SharedKey, Ciphertext<PARAMS>) {
+    encaps_with_rng::<PARAMS>(ek_mlkem, &mut OsRng)
+}
+
+/// # ML-KEM.Encaps (with a caller provided RNG)
+/// 
+/// Same as [encaps], but the 32 byte message **`m`** is drawn from **`rng`**, which can be any cryptographically secure RNG implementing `rand_core`'s traits
+/// 
+/// **Returns** a tuple containing the shared key (Party B's copy) and the cyphertext to be sent back
+pub fn encaps_with_rng<PARAMS: MlKemParams>(ek_mlkem: EncapsulationKey<PARAMS>, rng: &mut impl CryptoRngCore) -> (SharedKey, Ciphertext<PARAMS>) {
+    let m = Secret::new(crypt::random_bytes::<32>(rng));
+
+    encaps_internal::<PARAMS>(ek_mlkem, *m)
+}
+
+/// # ML-KEM.Encaps_internal
+/// 
+/// Deterministic version of [encaps], the 32 byte message **`m`** is provided by the caller instead of being randomly generated. Useful for running known-answer tests.
+/// 
+/// **Returns** a tuple containing the shared key (Party B's copy) and the cyphertext to be sent back
+pub fn encaps_internal<PARAMS: MlKemParams>(ek_mlkem: EncapsulationKey<PARAMS>, m: [u8; 32]) -> (SharedKey, Ciphertext<PARAMS>) {
+    let a = kpke::expand_a::<PARAMS>(ek_mlkem.rho());
+
+    encaps_with_a(&ek_mlkem, &a, &ek_mlkem.hash(), m)
+}
 
-    let ek_hash = crypt::h(&ek_mlkem.serialize().into_vec());
+// Encaps_internal with the matrix A and H(ek) already computed, shared with PreparedEncapsulationKey
+fn encaps_with_a<PARAMS: MlKemParams>(ek_mlkem: &EncapsulationKey<PARAMS>, a: &Matrix, ek_hash: &[u8; 32], m: [u8; 32]) -> (SharedKey, Ciphertext<PARAMS>) {
+    let m = Secret::new(PARAMS::Variant::encaps_message(m));
 
-    let mut combined = [0u8; 64];
+    let mut combined = Secret::new([0u8; 64]);
 
-    combined[..32].copy_from_slice(&m);
-    combined[32..].copy_from_slice(&ek_hash);
+    combined[..32].copy_from_slice(&*m);
+    combined[32..].copy_from_slice(ek_hash);
 
     let (key, r) = crypt::g::<64>(&combined);
+    let (key, r) = (Secret::new(key), Secret::new(r));
     
-    let m: Compressed<1, Ring> = Compressed::<1, Ring>::deserialize(&m.view_bits::<BitOrder>().to_bitvec());
+    let m = Secret::new(decode_message(&*m).unwrap()); // Always 32 bytes
 
     // Encrypt the encapsulation key
-    let c = kpke::encrypt::<PARAMS>(ek_mlkem, m, r);
+    let c = kpke::encrypt::<PARAMS>(a, &ek_mlkem.inner, &m, *r);
+
+    let key = PARAMS::Variant::shared_key(*key, || c.serialize().into_vec());
 
-    (key, c)
+    (SharedKey::new(key), Ciphertext::new(c))
+}
+
+// The modulus check from FIPS 203 section 7.2, a well formed encapsulation key re-encodes to the same bytes after decoding, which only holds if every coefficient of `t` is reduced modulo Q.
+// Every way of deserializing an encapsulation key runs it, so keys from an untrusted party are checked before they can be used
+pub(crate) fn modulus_check(ek_mlkem: &MlKemEncapsulationKey) -> Result<(), Error> {
+    let (t, _) = ek_mlkem;
+
+    match t.data.iter().all(|ring| ring.data.iter().all(|&coefficient| (coefficient as u16) < Q)) {
+        true => Ok(()),
+        false => Err(Error::EncapsulationKeyModulus)
+    }
+}
+
+/// # Decapsulation Key Check
+/// 
+/// The hash check from FIPS 203 section 7.3, the hash stored in the decapsulation key must equal H(ek) of the encapsulation key stored alongside it. The modulus check on that encapsulation key already ran when it was decoded.
+/// 
+/// **Returns** an [Error] describing the first check that failed
+pub fn check_decapsulation_key<PARAMS: MlKemParams>(dk_mlkem: &DecapsulationKey<PARAMS>) -> Result<(), Error> {
+    let ek = dk_mlkem.encapsulation_key();
+
+    match ek.hash() == *dk_mlkem.hash() {
+        true => Ok(()),
+        false => Err(Error::DecapsulationKeyHash)
+    }
+}
+
+/// # Decapsulation Key Loading
+/// 
+/// Deserializes a decapsulation key (e.g. one read back from disk) while performing all the FIPS 203 section 7.3 input checks, the length and encoding checks, then the hash check of [check_decapsulation_key]. It's the same as the decapsulation key's [MlKemDeserialize::try_deserialize], which runs these checks too.
+/// 
+/// Expects a generic parameter to be provided, **`PARAMS`**, the ML-KEM parameters which should implement the trait [MlKemParams]
+/// 
+/// **Returns** the decapsulation key, or an [Error] describing the first check that failed
+pub fn load_decapsulation_key<PARAMS: MlKemParams>(bitvec: &BitVec<u8, BitOrder>) -> Result<DecapsulationKey<PARAMS>, Error> {
+    DecapsulationKey::<PARAMS>::try_deserialize(bitvec)
+}
+
+// The checks on a key in its plain byte form, behind every decapsulation key loader
+pub(crate) fn load_decapsulation_key_bytes<PARAMS: MlKemParams>(bytes: &[u8]) -> Result<DecapsulationKey<PARAMS>, Error> {
+    let dk_mlkem = decode_decapsulation_key(bytes, PARAMS::K).map(DecapsulationKey::new)?;
+
+    check_decapsulation_key(&dk_mlkem)?;
+
+    Ok(dk_mlkem)
 }
 
 /// # ML-KEM.Decaps
 /// 
 /// Expects a generic parameter to be provided, **`PARAMS`**, the ML-KEM parameters which should implement the trait [MlKemParams]
 /// 
-/// Takes in the cyphertext (from the other party) and the decapsulation key (from KeyGen earlier) as inputs, both of which are expected to already be deserialized and match the type signatures of [MlKemCyphertext] and [MlKemDecapsulationKey] respectively.
+/// Takes in the cyphertext (from the other party) and the decapsulation key (from KeyGen earlier) as inputs, both of which are expected to already be deserialized into a [Ciphertext] and [DecapsulationKey] respectively.
 /// 
 /// **Returns** the shared key (Party A's copy) if the decapsulation is successful (cyphertexts match), otherwise it returns a implicit rejection key.
-pub fn decaps<PARAMS: MlKemParams>(c: MlKemCyphertext<{PARAMS::K}, {PARAMS::D_U}, {PARAMS::D_V}>, dk_mlkem: MlKemDecapsulationKey<{PARAMS::K}>) -> [u8; 32] where
-    [(); PARAMS::K]: ,
-    [(); 384 * PARAMS::K + 32]: ,
-    [(); 32*(PARAMS::D_U * PARAMS::K + PARAMS::D_V)]: ,
-    [(); 64 * PARAMS::ETA_1]: ,
-    [(); 64 * PARAMS::ETA_2]: ,
-    [(); 384 * PARAMS::K + 32]:
-{
-    let (dk, ek, hash, z) = dk_mlkem;
+pub fn decaps<PARAMS: MlKemParams>(c: Ciphertext<PARAMS>, dk_mlkem: DecapsulationKey<PARAMS>) -> SharedKey {
+    decaps_ref(&c, &dk_mlkem)
+}
 
-    let m = kpke::decrypt::<PARAMS>(dk, c.clone());
+// Decaps without taking ownership of its inputs, so a key can be reused without cloning its secrets
+pub(crate) fn decaps_ref<PARAMS: MlKemParams>(c: &Ciphertext<PARAMS>, dk_mlkem: &DecapsulationKey<PARAMS>) -> SharedKey {
+    let a = kpke::expand_a::<PARAMS>(dk_mlkem.encapsulation_key().rho());
 
-    let mut combined = [0u8; 64];
+    decaps_choice(c, dk_mlkem, &a).0
+}
 
-    combined[..32].copy_from_slice(m.serialize().as_raw_slice());
-    combined[32..].copy_from_slice(&hash);
+// Test mode of Decaps, also reports whether the implicit rejection key was returned. Never exposed, as that is exactly what implicit rejection hides
+#[cfg(test)]
+pub(crate) fn decaps_reporting_rejection<PARAMS: MlKemParams>(c: &Ciphertext<PARAMS>, dk_mlkem: &DecapsulationKey<PARAMS>) -> (SharedKey, bool) {
+    let a = kpke::expand_a::<PARAMS>(dk_mlkem.encapsulation_key().rho());
+    let (key, accepted) = decaps_choice(c, dk_mlkem, &a);
+
+    (key, !bool::from(accepted))
+}
+
+// Decaps with the matrix A already expanded, along with the constant time result of the cyphertext comparison
+fn decaps_choice<PARAMS: MlKemParams>(c: &Ciphertext<PARAMS>, dk_mlkem: &DecapsulationKey<PARAMS>, a: &Matrix) -> (SharedKey, Choice) {
+    let (dk, ek, hash, z) = &dk_mlkem.inner;
+    let c = &c.inner;
+
+    let m = Secret::new(kpke::decrypt::<PARAMS>(dk, c.clone()));
+
+    let mut combined = Secret::new([0u8; 64]);
+
+    let mut m_bits = m.serialize();
+    combined[..32].copy_from_slice(m_bits.as_raw_slice());
+    combined[32..].copy_from_slice(hash);
+    m_bits.as_raw_mut_slice().wipe();
 
     let (key, rand) = crypt::g::<64>(&combined);
+    let (key, rand) = (Secret::new(key), Secret::new(rand));
 
-    let c_prime = kpke::encrypt::<PARAMS>(ek, m, rand); // Should be same as encaps
+    let c_prime = kpke::encrypt::<PARAMS>(a, ek, &m, *rand); // Should be same as encaps
 
-    match (c.0 == c_prime.0) && (c.1 == c_prime.1) {
-        true => key,
-        false => crypt::j([&z, c.serialize().as_raw_slice()].concat())
-    }
+    // Both keys are always derived and the comparison is done on the encoded bytes, so nothing about where c and c' differ leaks through timing
+    let c_bytes = c.serialize().into_vec();
+    let c_prime_bytes = c_prime.serialize().into_vec();
+
+    let key = Secret::new(PARAMS::Variant::shared_key(*key, || c_bytes.clone()));
+    let rejection_key = Secret::new(PARAMS::Variant::rejection_key(z, &c_bytes));
+
+    let accepted = c_bytes.ct_eq(&c_prime_bytes);
+
+    (SharedKey::new(<[u8; 32]>::conditional_select(&rejection_key, &key, accepted)), accepted)
 }
 
 
@@ -143,18 +612,311 @@ mod tests {
         let dk = dk.serialize();
 
         //ML-KEM.Encaps
-        let ek = MlKemEncapsulationKey::<{PARAMS::K}>::deserialize(&ek);
+        let ek = EncapsulationKey::<PARAMS>::deserialize(&ek);
         
         let (key, c) = encaps::<PARAMS>(ek);
 
         let c = c.serialize();
 
         //ML-KEM.Decaps
-        let dk = MlKemDecapsulationKey::<{PARAMS::K}>::deserialize(&dk);
-        let c = MlKemCyphertext::<{PARAMS::K}, {PARAMS::D_U}, {PARAMS::D_V}>::deserialize(&c);
+        let dk = DecapsulationKey::<PARAMS>::deserialize(&dk);
+        let c = Ciphertext::<PARAMS>::deserialize(&c);
 
         let key_prime = decaps::<PARAMS>(c, dk);
 
         assert_eq!(key, key_prime);
     }
+
+    #[test]
+    fn test_mlkem_bytes() {
+        use super::*;
+        type PARAMS = MlKem1024;
+
+        //ML-KEM.KeyGen
+        let (ek, dk) = key_gen::<PARAMS>();
+
+        let ek: [u8; PARAMS::ENCAPSULATION_KEY_SIZE] = ek.to_bytes();
+        let dk: [u8; PARAMS::DECAPSULATION_KEY_SIZE] = dk.to_bytes();
+
+        //ML-KEM.Encaps
+        let ek = EncapsulationKey::<PARAMS>::from_bytes(&ek).unwrap();
+        
+        let (key, c) = encaps::<PARAMS>(ek);
+
+        let c: [u8; PARAMS::CYPHERTEXT_SIZE] = c.to_bytes();
+
+        //ML-KEM.Decaps
+        let dk = DecapsulationKey::<PARAMS>::from_bytes(&dk).unwrap();
+        let c = Ciphertext::<PARAMS>::from_bytes(&c).unwrap();
+
+        let key_prime = decaps::<PARAMS>(c, dk);
+
+        assert_eq!(key, key_prime);
+    }
+
+    #[test]
+    fn test_with_rng() {
+        use super::*;
+        use rand_core::{CryptoRng, RngCore};
+        type PARAMS = MlKem768;
+
+        // Counts upwards from 0, so the seeds it hands out are known
+        struct CountingRng(u8);
+
+        impl RngCore for CountingRng {
+            fn next_u32(&mut self) -> u32 {
+                rand_core::impls::next_u32_via_fill(self)
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                rand_core::impls::next_u64_via_fill(self)
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                for byte in dest.iter_mut() {
+                    *byte = self.0;
+                    self.0 = self.0.wrapping_add(1);
+                }
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        impl CryptoRng for CountingRng {}
+
+        let mut rng = CountingRng(0);
+
+        // d is drawn first, then z, then m
+        let (ek, dk) = key_gen_with_rng::<PARAMS>(&mut rng);
+        let (ek_internal, dk_internal) = key_gen_internal::<PARAMS>(core::array::from_fn(|i| i as u8), core::array::from_fn(|i| 32 + i as u8));
+
+        assert!(ek == ek_internal);
+        assert_eq!(dk.serialize(), dk_internal.serialize());
+
+        let (key, c) = encaps_with_rng::<PARAMS>(ek, &mut rng);
+        let (key_internal, c_internal) = encaps_internal::<PARAMS>(ek_internal, core::array::from_fn(|i| 64 + i as u8));
+
+        assert_eq!(key, key_internal);
+        assert!(c == c_internal);
+        assert_eq!(decaps::<PARAMS>(c, dk), key);
+    }
+
+    #[test]
+    fn test_implicit_rejection() {
+        use super::*;
+        type PARAMS = MlKem768;
+
+        let (ek, dk) = key_gen::<PARAMS>();
+        let (key, c) = encaps::<PARAMS>(ek);
+        let z = dk.inner.3;
+
+        // Untouched cyphertext takes the shared key branch
+        assert_eq!(decaps::<PARAMS>(c.clone(), dk.clone()), key);
+
+        // A difference in the first byte of u, the last byte of u, or the last byte of v all take the rejection branch
+        let c_bytes = c.serialize();
+        for bit in [0, 8 * 32 * PARAMS::D_U * PARAMS::K - 1, c_bytes.len() - 1] {
+            let mut tampered = c_bytes.clone();
+            let flipped = !tampered[bit];
+            tampered.set(bit, flipped);
+
+            let rejection_key = crypt::j([z.as_slice(), tampered.as_raw_slice()].concat());
+            let c = Ciphertext::<PARAMS>::deserialize(&tampered);
+
+            let key_prime = decaps::<PARAMS>(c, dk.clone());
+            assert_eq!(*key_prime.as_bytes(), rejection_key);
+            assert_ne!(key_prime, key);
+        }
+    }
+
+    #[test]
+    fn test_encapsulation_key_check() {
+        use super::*;
+        use bitvec::field::BitField;
+        type PARAMS = MlKem768;
+
+        let (ek, _dk) = key_gen::<PARAMS>();
+        assert!(modulus_check(&ek.inner).is_ok());
+        assert!(EncapsulationKey::<PARAMS>::try_deserialize(&ek.serialize()).is_ok());
+
+        // 12 bits can hold values up to 4095, anything from Q upwards is unreduced
+        for value in [Q, 4095] {
+            let mut ek_bytes = ek.serialize();
+            ek_bytes[..12].store_le::<u16>(value);
+            assert_eq!(EncapsulationKey::<PARAMS>::try_deserialize(&ek_bytes).err(), Some(Error::EncapsulationKeyModulus));
+
+            let ek_array = ek_bytes.as_raw_slice().try_into().unwrap();
+            assert_eq!(EncapsulationKey::<PARAMS>::from_bytes(&ek_array).err(), Some(Error::EncapsulationKeyModulus));
+        }
+    }
+
+    #[test]
+    fn test_decapsulation_key_check() {
+        use super::*;
+        use bitvec::field::BitField;
+        type PARAMS = MlKem768;
+
+        let (ek, dk) = key_gen::<PARAMS>();
+        assert!(dk.encapsulation_key() == &ek);
+        assert_eq!(*dk.hash(), ek.hash());
+
+        // The encapsulation key is the one inside the decapsulation key, not a second copy that could drift apart
+        assert!(core::ptr::eq(&dk.encapsulation_key().inner, &dk.inner.1));
+
+        let dk_bytes = dk.serialize();
+        assert!(load_decapsulation_key::<PARAMS>(&dk_bytes).is_ok());
+
+        // Truncated
+        let mut truncated = dk_bytes.clone();
+        truncated.truncate(dk_bytes.len() - 8);
+        assert_eq!(load_decapsulation_key::<PARAMS>(&truncated).err(), Some(Error::InvalidLength { expected: 768 * 3 + 96, found: 768 * 3 + 95 }));
+
+        // Stored H(ek) no longer matches, flip a bit of the hash which sits after dk_pke and ek
+        let mut bad_hash = dk_bytes.clone();
+        let hash_bit = 8 * (768 * 3 + 32);
+        let flipped = !bad_hash[hash_bit];
+        bad_hash.set(hash_bit, flipped);
+        assert_eq!(load_decapsulation_key::<PARAMS>(&bad_hash).err(), Some(Error::DecapsulationKeyHash));
+        assert_eq!(DecapsulationKey::<PARAMS>::try_deserialize(&bad_hash).err(), Some(Error::DecapsulationKeyHash));
+        assert!(std::panic::catch_unwind(|| DecapsulationKey::<PARAMS>::deserialize(&bad_hash)).is_err());
+
+        // Unreduced coefficient inside the embedded encapsulation key
+        let mut bad_ek = dk_bytes.clone();
+        bad_ek[8 * 384 * 3..][..12].store_le::<u16>(4095);
+        assert_eq!(load_decapsulation_key::<PARAMS>(&bad_ek).err(), Some(Error::EncapsulationKeyModulus));
+
+        // Unreduced coefficient in the secret vector
+        let mut bad_s = dk_bytes.clone();
+        bad_s[..12].store_le::<u16>(4095);
+        assert_eq!(load_decapsulation_key::<PARAMS>(&bad_s).err(), Some(Error::InvalidEncoding));
+
+        // The byte array loader runs the same checks
+        let mut bad_hash_bytes = dk.to_bytes();
+        bad_hash_bytes[768 * 3 + 32] ^= 1;
+        assert_eq!(DecapsulationKey::<PARAMS>::from_bytes(&bad_hash_bytes).err(), Some(Error::DecapsulationKeyHash));
+        assert!(DecapsulationKey::<PARAMS>::from_bytes(&dk.to_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_try_deserialize() {
+        use super::*;
+        type PARAMS = MlKem768;
+
+        let (ek, dk) = key_gen::<PARAMS>();
+        let (_key, c) = encaps::<PARAMS>(ek.clone());
+
+        let ek_bytes = ek.serialize();
+        let c_bytes = c.serialize();
+
+        assert!(EncapsulationKey::<PARAMS>::try_deserialize(&ek_bytes).is_ok());
+        assert!(Ciphertext::<PARAMS>::try_deserialize(&c_bytes).is_ok());
+
+        // Truncated packets are errors rather than panics
+        let empty = BitVec::new();
+        assert_eq!(EncapsulationKey::<PARAMS>::try_deserialize(&empty).err(), Some(Error::InvalidLength { expected: 1184, found: 0 }));
+        assert_eq!(DecapsulationKey::<PARAMS>::try_deserialize(&empty).err(), Some(Error::InvalidLength { expected: 2400, found: 0 }));
+        assert_eq!(Ciphertext::<PARAMS>::try_deserialize(&c_bytes[..8 * 1000].to_bitvec()).err(), Some(Error::InvalidLength { expected: 1088, found: 1000 }));
+        assert_eq!(decode_message(&c_bytes.as_raw_slice()[..1]).err(), Some(Error::InvalidLength { expected: 32, found: 1 }));
+
+        // Inputs from another parameter set are recognized
+        let (ek_512, dk_512) = key_gen::<MlKem512>();
+        let (_key, c_1024) = encaps::<MlKem1024>(key_gen::<MlKem1024>().0);
+        assert_eq!(EncapsulationKey::<PARAMS>::try_deserialize(&ek_512.serialize()).err(), Some(Error::ParameterMismatch { expected: 3, found: 2 }));
+        assert_eq!(DecapsulationKey::<PARAMS>::try_deserialize(&dk_512.serialize()).err(), Some(Error::ParameterMismatch { expected: 3, found: 2 }));
+        assert_eq!(Ciphertext::<PARAMS>::try_deserialize(&c_1024.serialize()).err(), Some(Error::ParameterMismatch { expected: 3, found: 4 }));
+
+        assert!(DecapsulationKey::<PARAMS>::try_deserialize(&dk.serialize()).is_ok());
+    }
+
+    #[test]
+    fn test_decapsulation_key_seed() {
+        use super::*;
+        type PARAMS = MlKem768;
+
+        let d: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let z: [u8; 32] = core::array::from_fn(|i| 32 + i as u8);
+        let seed = DecapsulationKeySeed::new(d, z);
+
+        // The seed form is d || z
+        let seed_bytes = seed.to_bytes();
+        assert_eq!(seed_bytes, core::array::from_fn(|i| i as u8));
+
+        // Storing and loading the seed gives back the same key pair
+        let seed = DecapsulationKeySeed::from_bytes(&seed_bytes).unwrap();
+        let (ek, dk) = seed.expand::<PARAMS>();
+        let (ek_internal, dk_internal) = key_gen_internal::<PARAMS>(d, z);
+
+        assert!(ek == ek_internal);
+        assert_eq!(dk.to_bytes(), dk_internal.to_bytes());
+        assert_eq!(check_decapsulation_key(&dk), Ok(()));
+
+        // Each parameter set expands the seed to its own key pair
+        assert_eq!(seed.expand::<MlKem512>().1.to_bytes().len(), MlKem512::DECAPSULATION_KEY_SIZE);
+
+        assert_eq!(DecapsulationKeySeed::try_deserialize(&dk.serialize()).err(), Some(Error::InvalidLength { expected: 64, found: 2400 }));
+
+        // 512 bits that don't start on a byte boundary
+        let mut offset = BitVec::<u8, BitOrder>::repeat(false, 8 * 65);
+        offset[3..3 + 512].copy_from_bitslice(&seed.serialize());
+        let offset = offset[3..3 + 512].to_bitvec();
+        assert_eq!(DecapsulationKeySeed::try_deserialize(&offset).unwrap().to_bytes(), seed_bytes);
+        assert_eq!(format!("{:?}", seed), "DecapsulationKeySeed(..)");
+    }
+
+    #[test]
+    fn test_pairwise_consistency() {
+        use super::*;
+        type PARAMS = MlKem512;
+
+        let (ek, dk) = key_gen_checked::<PARAMS>().unwrap();
+        assert_eq!(check_key_pair(&ek, &dk), Ok(()));
+
+        // Keys loaded from storage
+        let ek = EncapsulationKey::<PARAMS>::from_bytes(&ek.to_bytes()).unwrap();
+        let dk = load_decapsulation_key::<PARAMS>(&dk.serialize()).unwrap();
+        assert_eq!(check_key_pair(&ek, &dk), Ok(()));
+
+        // Keys that don't belong together
+        let (other_ek, other_dk) = key_gen::<PARAMS>();
+        assert_eq!(check_key_pair(&other_ek, &dk), Err(Error::PairwiseConsistency));
+        assert_eq!(check_key_pair(&ek, &other_dk), Err(Error::PairwiseConsistency));
+    }
+
+    #[test]
+    fn test_prepared_keys() {
+        use super::*;
+        type PARAMS = MlKem1024;
+
+        let (ek, dk) = key_gen::<PARAMS>();
+        let prepared_ek = ek.prepare();
+        let prepared_dk = dk.prepare();
+
+        assert_eq!(*prepared_ek.hash(), ek.hash());
+        assert_eq!(prepared_ek.hash(), dk.hash());
+
+        // Same outputs as the unprepared keys
+        for m in [[0u8; 32], [0xA5; 32]] {
+            let (key, c) = prepared_ek.encaps_internal(m);
+            let (key_unprepared, c_unprepared) = encaps_internal::<PARAMS>(ek.clone(), m);
+
+            assert_eq!(key, key_unprepared);
+            assert!(c == c_unprepared);
+            assert_eq!(prepared_dk.decaps(&c), key);
+        }
+
+        // The cached matrix is not changed by use
+        let (key, c) = prepared_ek.encaps();
+        assert_eq!(prepared_dk.decaps(&c), key);
+        assert_eq!(decaps::<PARAMS>(c.clone(), dk.clone()), key);
+
+        // Implicit rejection goes through the cached matrix too
+        let mut c_bits = c.serialize();
+        let flipped = !c_bits[0];
+        c_bits.set(0, flipped);
+        let c = Ciphertext::<PARAMS>::deserialize(&c_bits);
+        assert_eq!(prepared_dk.decaps(&c), decaps::<PARAMS>(c.clone(), dk));
+    }
 }
\ No newline at end of file
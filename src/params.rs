@@ -11,15 +11,54 @@ pub const Q32: u32 = 3329;
 pub const Q64: u64 = 3329;
 pub const ZETA: u16 = 17;
 
+use std::marker::PhantomData;
+use crate::variant::*;
+
+/// Fixed size byte array, implemented for every `[u8; N]`
+/// 
+/// Used for the associated types of [MlKemParams] that give the serialized sizes of a parameter set
+pub trait ByteArray: AsRef<[u8]> + AsMut<[u8]> + Clone {
+    /// Length of the array in bytes
+    const LEN: usize;
+
+    /// An array of zeroes
+    fn zeroed() -> Self;
+}
+
+impl<const N: usize> ByteArray for [u8; N] {
+    const LEN: usize = N;
+
+    fn zeroed() -> Self {
+        [0u8; N]
+    }
+}
+
 /// Trait for adding parameter values to the 3 parameter set structs.
 /// 
-/// You can make new functions generic over all 3 parameter sets by using this trait as a bound.
+/// You can make new functions generic over all 3 parameter sets by using this trait as a bound, no other bounds are needed.
 pub trait MlKemParams {
     const K: usize;
     const ETA_1: usize;
     const ETA_2: usize;
     const D_U: usize;
     const D_V: usize;
+
+    /// Size in bytes of a serialized encapsulation key
+    const ENCAPSULATION_KEY_SIZE: usize = 384 * Self::K + 32;
+    /// Size in bytes of a serialized decapsulation key
+    const DECAPSULATION_KEY_SIZE: usize = 768 * Self::K + 96;
+    /// Size in bytes of a serialized cyphertext
+    const CYPHERTEXT_SIZE: usize = 32 * (Self::D_U * Self::K + Self::D_V);
+
+    /// Byte array holding a serialized encapsulation key, `[u8; ENCAPSULATION_KEY_SIZE]`
+    type EncapsulationKeyBytes: ByteArray;
+    /// Byte array holding a serialized decapsulation key, `[u8; DECAPSULATION_KEY_SIZE]`
+    type DecapsulationKeyBytes: ByteArray;
+    /// Byte array holding a serialized cyphertext, `[u8; CYPHERTEXT_SIZE]`
+    type CyphertextBytes: ByteArray;
+
+    /// The algorithm variant the parameter set runs, see [crate::variant]
+    type Variant: Variant;
 }
 
 pub struct MlKem512;
@@ -29,6 +68,10 @@ impl MlKemParams for MlKem512 {
     const ETA_2: usize = 2;
     const D_U: usize = 10;
     const D_V: usize = 4;
+    type EncapsulationKeyBytes = [u8; 800];
+    type DecapsulationKeyBytes = [u8; 1632];
+    type CyphertextBytes = [u8; 768];
+    type Variant = MlKemFinal;
 }
 
 pub struct MlKem768;
@@ -38,6 +81,10 @@ impl MlKemParams for MlKem768 {
     const ETA_2: usize = 2;
     const D_U: usize = 10;
     const D_V: usize = 4;
+    type EncapsulationKeyBytes = [u8; 1184];
+    type DecapsulationKeyBytes = [u8; 2400];
+    type CyphertextBytes = [u8; 1088];
+    type Variant = MlKemFinal;
 }
 
 pub struct MlKem1024;
@@ -47,4 +94,44 @@ impl MlKemParams for MlKem1024 {
     const ETA_2: usize = 2;
     const D_U: usize = 11;
     const D_V: usize = 5;
-}
\ No newline at end of file
+    type EncapsulationKeyBytes = [u8; 1568];
+    type DecapsulationKeyBytes = [u8; 3168];
+    type CyphertextBytes = [u8; 1568];
+    type Variant = MlKemFinal;
+}
+
+/// Runs the parameter set **`PARAMS`** under the algorithm variant **`V`** instead of the final FIPS 203
+/// 
+/// e.g. `key_gen::<WithVariant<MlKem768, KyberR3>>()` generates a Kyber768 key pair
+pub struct WithVariant<PARAMS: MlKemParams, V: Variant>(PhantomData<(PARAMS, V)>);
+impl<PARAMS: MlKemParams, V: Variant> MlKemParams for WithVariant<PARAMS, V> {
+    const K: usize = PARAMS::K;
+    const ETA_1: usize = PARAMS::ETA_1;
+    const ETA_2: usize = PARAMS::ETA_2;
+    const D_U: usize = PARAMS::D_U;
+    const D_V: usize = PARAMS::D_V;
+    type EncapsulationKeyBytes = PARAMS::EncapsulationKeyBytes;
+    type DecapsulationKeyBytes = PARAMS::DecapsulationKeyBytes;
+    type CyphertextBytes = PARAMS::CyphertextBytes;
+    type Variant = V;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The byte array types have to be written out by hand, so check them against the sizes FIPS 203 gives
+    fn check_sizes<PARAMS: MlKemParams>() {
+        assert_eq!(<PARAMS::EncapsulationKeyBytes as ByteArray>::LEN, PARAMS::ENCAPSULATION_KEY_SIZE);
+        assert_eq!(<PARAMS::DecapsulationKeyBytes as ByteArray>::LEN, PARAMS::DECAPSULATION_KEY_SIZE);
+        assert_eq!(<PARAMS::CyphertextBytes as ByteArray>::LEN, PARAMS::CYPHERTEXT_SIZE);
+    }
+
+    #[test]
+    fn test_sizes() {
+        check_sizes::<MlKem512>();
+        check_sizes::<MlKem768>();
+        check_sizes::<MlKem1024>();
+        check_sizes::<WithVariant<MlKem1024, KyberR3>>();
+    }
+}
@@ -0,0 +1,127 @@
+use crate::params::*;
+
+// Vectors generated by RustCrypto's ml-kem 0.2.1 (and checked against SHAKE256(z || c) directly), from the same seeds as the variant tests.
+// tools/refvectors regenerates them with `cargo run -- rejection`.
+// Each cyphertext is the honest one from Encaps_internal with one of these modifications
+const D: &str = "7AF65022E0A472ED6388638EA29D82DA68B4CF9FFDF2B67CD708EA5A370C6A7C";
+const Z: &str = "B32D2AABD658245B07F4B3B91DDDCABECD95523A4DBAB3FF5B58FC1027D949D3";
+const M: &str = "034FF14A56249C2521D4279EBA3D04931CC892BBC45002B5B33D9F0188ACBAF6";
+
+#[derive(Clone, Copy, Debug)]
+enum Modification {
+    None,
+    // Lowest bit of the first byte of u
+    UFirstBit,
+    // Highest bit of the last byte of u
+    ULastBit,
+    // Lowest bit of the first byte of v
+    VFirstBit,
+    // Highest bit of the last byte of v
+    VLastBit,
+    // Every compressed value 0
+    AllZero,
+    // Every compressed value 2^d - 1
+    AllMax,
+}
+
+struct RejectionVector {
+    modification: Modification,
+    key: &'static str,
+    rejected: bool
+}
+
+const MLKEM512: [RejectionVector; 7] = [
+    RejectionVector { modification: Modification::None, key: "B2DAC7856CA45F0881513C04408AE1542F8EA231EB8D02CEC75673B6AFB32A64", rejected: false },
+    RejectionVector { modification: Modification::UFirstBit, key: "B02486726D409970BAA1EC9F556B1936E0FED3103E131A84FD60A373892C5015", rejected: true },
+    RejectionVector { modification: Modification::ULastBit, key: "0C921DDCA03D056013542F8903C9E1A70152E0CB816D3C6C6E4069F4FE779BD4", rejected: true },
+    RejectionVector { modification: Modification::VFirstBit, key: "9A91168A6226C9926AC5FB8155A229F43D208EFD5157E367BFF728CD1E6FF7B9", rejected: true },
+    RejectionVector { modification: Modification::VLastBit, key: "B02BAEFF46CFE17492161982EED42A5A6B7741D5353BF880483B8DEBE6500F9F", rejected: true },
+    RejectionVector { modification: Modification::AllZero, key: "D50FD4AE72EBACA8A2AF39AB7F4D5A4C998751C2CE8407AF4DE4421CBF91FC49", rejected: true },
+    RejectionVector { modification: Modification::AllMax, key: "C9B6CFBCB9DD7C42E4EE1E284D1AD2DD73130F0CEB0D10E6E0157D0965543E7F", rejected: true },
+];
+
+const MLKEM768: [RejectionVector; 7] = [
+    RejectionVector { modification: Modification::None, key: "4918DFB8C37E7F0F560A73F643FBDA59FA75E208C27E365D145E21F323BFB69F", rejected: false },
+    RejectionVector { modification: Modification::UFirstBit, key: "9DCA9D327DF4747E62D42EBD8502EE99132824128DD0BCB3FEA6016CBA5121C5", rejected: true },
+    RejectionVector { modification: Modification::ULastBit, key: "7571D493CA46144ECFF335289F1C1961ED12230063C50F45F852EC2A4E1DE90B", rejected: true },
+    RejectionVector { modification: Modification::VFirstBit, key: "84188669872E4B6CD9B384ED5C0AF96DD3095188F9265E23BF2EC54BD292D38D", rejected: true },
+    RejectionVector { modification: Modification::VLastBit, key: "6CDD7749A22A9958F710FF4333595413CF68906723BE1DA6E46C8EBC1318746C", rejected: true },
+    RejectionVector { modification: Modification::AllZero, key: "2F1E32865AE52E562BDF1BE061BB2AD2EEC5367996FB83ABBB10A4C75E68470B", rejected: true },
+    RejectionVector { modification: Modification::AllMax, key: "E6C61D5CE396FA3912510EAAA3B5ACECC551E4CC52BCC9798E4CB1A0F3DBFCCE", rejected: true },
+];
+
+const MLKEM1024: [RejectionVector; 7] = [
+    RejectionVector { modification: Modification::None, key: "ADC0CE86A6AAFD8EAC9DF29FD19EDF634858ECAA113B57942D343AF77DBE2B90", rejected: false },
+    RejectionVector { modification: Modification::UFirstBit, key: "D049FE6871DA8FED80DB4F65C6F473CF4A76D5621D823CFB3939BB715B002C4B", rejected: true },
+    RejectionVector { modification: Modification::ULastBit, key: "9B7F17675AB07957FC9A1BAAE3BA04412A0C723F590F6893AE45DD4B41662534", rejected: true },
+    RejectionVector { modification: Modification::VFirstBit, key: "B345967B9C8263EF0581A3BAE15B88ED5678667AE6BC4F9252823B07E1EC7C70", rejected: true },
+    RejectionVector { modification: Modification::VLastBit, key: "C4E24D28D1C29D668BF1BBE42F95B2DC10D8FC622140A15E20F860B73075CAD9", rejected: true },
+    RejectionVector { modification: Modification::AllZero, key: "00DBEECDF800E9E2341E10EC89A45360802E3DCC7878501DB1D74DB0FDE2380D", rejected: true },
+    RejectionVector { modification: Modification::AllMax, key: "2EC119825E3FBF119BDD984281079DD46940024BF31C8D7D1745FD5AD20629BF", rejected: true },
+];
+
+mod test {
+    use super::*;
+    use crate::crypt;
+    use crate::mlkem::*;
+    use crate::serialize::*;
+    use bitvec::view::BitView;
+
+    fn from_hex(s: &str) -> [u8; 32] {
+        core::array::from_fn(|i| u8::from_str_radix(&s[2*i..2*i + 2], 16).unwrap())
+    }
+
+    fn modify<PARAMS: MlKemParams>(c: &[u8], modification: Modification) -> Vec<u8> {
+        let u_len = 32 * PARAMS::D_U * PARAMS::K;
+        let mut c = c.to_vec();
+
+        match modification {
+            Modification::None => {},
+            Modification::UFirstBit => c[0] ^= 0x01,
+            Modification::ULastBit => c[u_len - 1] ^= 0x80,
+            Modification::VFirstBit => c[u_len] ^= 0x01,
+            Modification::VLastBit => *c.last_mut().unwrap() ^= 0x80,
+            Modification::AllZero => c.fill(0x00),
+            Modification::AllMax => c.fill(0xFF),
+        }
+
+        c
+    }
+
+    fn check_rejection<PARAMS: MlKemParams>(vectors: &[RejectionVector]) {
+        let (ek, dk) = key_gen_internal::<PARAMS>(from_hex(D), from_hex(Z));
+        let (_key, c) = encaps_internal::<PARAMS>(ek, from_hex(M));
+        let c_bytes = c.serialize().into_vec();
+
+        for vector in vectors {
+            let modified = modify::<PARAMS>(&c_bytes, vector.modification);
+            let c = Ciphertext::<PARAMS>::try_deserialize(&modified.view_bits().to_bitvec()).unwrap();
+
+            let (key, rejected) = decaps_reporting_rejection::<PARAMS>(&c, &dk);
+
+            assert_eq!(rejected, vector.rejected, "{:?}", vector.modification);
+            assert_eq!(*key.as_bytes(), from_hex(vector.key), "{:?}", vector.modification);
+            if rejected {
+                assert_eq!(*key.as_bytes(), crypt::j([from_hex(Z).as_slice(), &modified].concat()), "{:?}", vector.modification);
+            }
+
+            // The public Decaps returns the same key, without saying which it was
+            assert_eq!(decaps::<PARAMS>(c, dk.clone()), key);
+        }
+    }
+
+    #[test]
+    fn rejection_512() {
+        check_rejection::<MlKem512>(&MLKEM512);
+    }
+
+    #[test]
+    fn rejection_768() {
+        check_rejection::<MlKem768>(&MLKEM768);
+    }
+
+    #[test]
+    fn rejection_1024() {
+        check_rejection::<MlKem1024>(&MLKEM1024);
+    }
+}
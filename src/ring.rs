@@ -1,7 +1,9 @@
 use crate::params;
-use crate::util::*;
+use crate::secret::Secret;
+#[cfg(target_arch = "x86_64")]
+use crate::avx2;
 
-use std::sync::OnceLock;
+use std::fmt;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum RingRepresentation {
@@ -11,69 +13,87 @@ pub enum RingRepresentation {
 
 #[derive(Clone, Debug)]
 pub struct Ring {
-    pub data: [u16; 256],
+    pub data: [i16; 256],
     pub t: RingRepresentation
 }
 
 
-impl ToString for Ring {
-    fn to_string(&self) -> String {
-        let mut s = String::new();
-        for i in 0..256 {
-            s.push_str(format!("{:X}", self.data[i]).as_str());
-            s.push(',');
+impl fmt::Display for Ring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for coefficient in self.data.iter() {
+            write!(f, "{:X},", coefficient)?;
         }
-        s
+        Ok(())
     }
 }
 
-static ZETA_POWERS_MULT: OnceLock<[u16; 128]> = OnceLock::new();
-static ZETA_POWERS_NTT: OnceLock<[u16; 128]> = OnceLock::new();
+const Q: i16 = params::Q as i16;
 
-impl Ring {
-    pub const ZEROES_NTT : Ring       = Ring { data: [0; 256], t: RingRepresentation::NTT };
-    pub const ZEROES_DEGREE255 : Ring = Ring { data: [0; 256], t: RingRepresentation::Degree255 };
+// q^-1 mod 2^16, as a signed value
+pub const QINV: i16 = -3327;
+// R^2 mod q, where R = 2^16 is the Montgomery factor
+pub const R2: i16 = 1353;
+// 128^-1 mod q, the scaling at the end of NTT^-1
+pub const INV_128: i16 = 3303;
 
-    fn get_zeta_powers_ntt() -> &'static [u16; 128] {
-        ZETA_POWERS_NTT.get_or_init(|| {
-            let mut zeta_powers = [0u16; 128];
-            zeta_powers[0] = 1;
-            for i in 1..128 {
-                zeta_powers[i] = fastmodpow(params::ZETA, bitrev7(i as u8)) as u16;
-            };
-            zeta_powers
-        })
-    }
-
-    fn get_zeta_powers_mult() -> &'static [u16; 128] {
-        ZETA_POWERS_MULT.get_or_init(|| {
-            let mut zeta_powers = [0u16; 128];
-            zeta_powers[0] = params::ZETA;
-            for i in 1..128 {
-                zeta_powers[i] = fastmodpow(params::ZETA, 2*bitrev7(i as u8) + 1) as u16;
-            }
-            zeta_powers
-        })
-    }
+// Montgomery multiplication, for |a * b| < q * 2^15 returns a * b * R^-1 mod q in (-q, q)
+// a * b - t * q is a multiple of R, so its two products share their low half and only the high halves are subtracted, which the compiler turns into SIMD multiply-high instructions
+pub const fn fqmul(a: i16, b: i16) -> i16 {
+    let t = a.wrapping_mul(b).wrapping_mul(QINV);
+    (((a as i32 * b as i32) >> 16) as i16).wrapping_sub(((t as i32 * Q as i32) >> 16) as i16)
+}
 
-    pub fn scalar_mul(&mut self, value: u16) {
-        let val = value as u32;
+// Barrett reduction, for any a returns a mod q in [-(q-1)/2, (q-1)/2]
+const fn barrett_reduce(a: i16) -> i16 {
+    const V: i32 = ((1 << 26) + Q as i32 / 2) / Q as i32;
+    let t = (V * a as i32 + (1 << 25)) >> 26;
+    (a as i32 - t * Q as i32) as i16
+}
 
-        for i in 0..256 {
-            self.data[i] = ((self.data[i] as u32 * val) % params::Q as u32) as u16;
-        }
-    }
+// Maps a value in (-q, q) to [0, q) by adding q to negative values, without branching
+pub const fn to_canonical(a: i16) -> i16 {
+    a + ((a >> 15) & Q)
+}
 
+// zeta^bitrev7(i) * R mod q, the NTT twiddle factors in the Montgomery domain
+pub const ZETA_POWERS_NTT: [i16; 128] = [
+    2285, 2571, 2970, 1812, 1493, 1422, 287, 202, 3158, 622, 1577, 182, 962, 2127, 1855, 1468,
+    573, 2004, 264, 383, 2500, 1458, 1727, 3199, 2648, 1017, 732, 608, 1787, 411, 3124, 1758,
+    1223, 652, 2777, 1015, 2036, 1491, 3047, 1785, 516, 3321, 3009, 2663, 1711, 2167, 126, 1469,
+    2476, 3239, 3058, 830, 107, 1908, 3082, 2378, 2931, 961, 1821, 2604, 448, 2264, 677, 2054,
+    2226, 430, 555, 843, 2078, 871, 1550, 105, 422, 587, 177, 3094, 3038, 2869, 1574, 1653,
+    3083, 778, 1159, 3182, 2552, 1483, 2727, 1119, 1739, 644, 2457, 349, 418, 329, 3173, 3254,
+    817, 1097, 603, 610, 1322, 2044, 1864, 384, 2114, 3193, 1218, 1994, 2455, 220, 2142, 1670,
+    2144, 1799, 2051, 794, 1819, 2475, 2459, 478, 3221, 3021, 996, 991, 958, 1869, 1522, 1628
+];
+
+// zeta^(2 * bitrev7(i) + 1) * R mod q, the base case multiplication factors in the Montgomery domain
+pub const ZETA_POWERS_MULT: [i16; 128] = [
+    2226, 1103, 430, 2899, 555, 2774, 843, 2486, 2078, 1251, 871, 2458, 1550, 1779, 105, 3224,
+    422, 2907, 587, 2742, 177, 3152, 3094, 235, 3038, 291, 2869, 460, 1574, 1755, 1653, 1676,
+    3083, 246, 778, 2551, 1159, 2170, 3182, 147, 2552, 777, 1483, 1846, 2727, 602, 1119, 2210,
+    1739, 1590, 644, 2685, 2457, 872, 349, 2980, 418, 2911, 329, 3000, 3173, 156, 3254, 75,
+    817, 2512, 1097, 2232, 603, 2726, 610, 2719, 1322, 2007, 2044, 1285, 1864, 1465, 384, 2945,
+    2114, 1215, 3193, 136, 1218, 2111, 1994, 1335, 2455, 874, 220, 3109, 2142, 1187, 1670, 1659,
+    2144, 1185, 1799, 1530, 2051, 1278, 794, 2535, 1819, 1510, 2475, 854, 2459, 870, 478, 2851,
+    3221, 108, 3021, 308, 996, 2333, 991, 2338, 958, 2371, 1869, 1460, 1522, 1807, 1628, 1701
+];
+
+impl Ring {
+    pub const ZEROES_NTT : Ring       = Ring { data: [0; 256], t: RingRepresentation::NTT };
+    pub const ZEROES_DEGREE255 : Ring = Ring { data: [0; 256], t: RingRepresentation::Degree255 };
+
+    // Coefficients are kept in [0, q) between operations, the signed representation only allows lazier reduction inside them
     pub fn add(&mut self, other: &Ring) -> &mut Self {
-        for i in 0..256 {
-            self.data[i] = (self.data[i] + other.data[i]) % params::Q;
+        for (coefficient, other) in self.data.iter_mut().zip(other.data.iter()) {
+            *coefficient = to_canonical(*coefficient + other - Q);
         }
         self
     }
 
     pub fn sub(&mut self, other: &Ring) -> &mut Self {
-        for i in 0..256 {
-            self.data[i] = (self.data[i] + params::Q - other.data[i]) % params::Q;
+        for (coefficient, other) in self.data.iter_mut().zip(other.data.iter()) {
+            *coefficient = to_canonical(*coefficient - other);
         }
         self
     }
@@ -83,52 +103,15 @@ impl Ring {
             panic!("Multiplication requires NTT form");
         }
 
-        let a = &mut self.data;
-        let b = &other.data;
-
-        let zeta_powers = Ring::get_zeta_powers_mult();
-
-        for i in 0usize..128usize {
-            let gamma = zeta_powers[i] as u64;
-
-            let r_1: u64 = (a[2*i] as u64) * (b[2*i] as u64) + gamma * (a[2*i + 1] as u64) * (b[2*i + 1] as u64); 
-            let r_2: u64 = (a[2*i] as u64) * (b[2*i + 1] as u64) + (a[2*i + 1] as u64) * (b[2*i] as u64);
-
-            a[2*i] = (r_1 % params::Q64) as u16;
-            a[2*i + 1] = (r_2 % params::Q64) as u16;
-        }
+        mult(&mut self.data, &other.data);
 
         self
     }
 
     // In-Place, transforms ring to NTT form
     pub fn ntt(&mut self) -> &mut Self {
-        const Q32: u32 = params::Q as u32;
-
-        let zeta_powers = Ring::get_zeta_powers_ntt();
-
         if let Ring { data, t: RingRepresentation::Degree255 } = self {
-            let mut k = 1;
-            let mut len = 128;
-
-            while len >= 2 {
-                let mut start = 0;
-                while start < 256 {
-                    let zeta = zeta_powers[k] as u32;
-
-                    k += 1;
-
-                    for j in start..(start + len) {
-                        let t = (zeta * data[j + len] as u32) % Q32;
-                        data[j + len] = ((data[j] as u32 + Q32 - t) % Q32) as u16;
-                        data[j] = ((data[j] as u32 + t) % Q32) as u16;
-                    }
-
-                    start += 2 * len;
-                }
-
-                len /= 2;
-            }
+            ntt(data);
 
             self.t = RingRepresentation::NTT;
             
@@ -139,31 +122,8 @@ impl Ring {
     }
 
     pub fn inverse_ntt(&mut self) -> &mut Self {
-        let zeta_powers = Ring::get_zeta_powers_ntt();
-
         if let Ring {  data, t: RingRepresentation::NTT } = self {
-            let mut k = 127;
-            let mut len = 2;
-            while len <= 128 {
-                let mut start = 0;
-                while start < 256 {
-                    let zeta = zeta_powers[k] as u32;
-
-                    k -= 1;
-
-                    for j in start..(start + len) {
-                        let t = data[j];
-                        data[j] = (data[j + len] + t) % params::Q;
-                        data[j + len] = ((zeta * (params::Q + data[j+len] - t) as u32) % params::Q32) as u16;
-                    }
-
-                    start += 2 * len;
-                }
-
-                len *= 2;
-            }
-
-            self.scalar_mul(3303);
+            inverse_ntt(data);
 
             self.t = RingRepresentation::Degree255;
 
@@ -174,6 +134,118 @@ impl Ring {
     }
 }
 
+// The kernels below pick the AVX2 backend when the CPU supports it, and fall back to the portable code otherwise
+fn ntt(data: &mut [i16; 256]) {
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: AVX2 support was just checked
+        return unsafe { avx2::ntt(data) };
+    }
+
+    ntt_portable(data)
+}
+
+fn inverse_ntt(data: &mut [i16; 256]) {
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: AVX2 support was just checked
+        return unsafe { avx2::inverse_ntt(data) };
+    }
+
+    inverse_ntt_portable(data)
+}
+
+fn mult(a: &mut [i16; 256], b: &[i16; 256]) {
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: AVX2 support was just checked
+        return unsafe { avx2::mult(a, b) };
+    }
+
+    mult_portable(a, b)
+}
+
+pub fn scalar_mul(data: &mut [i16; 256], value: i16) {
+    let value = fqmul(value, R2); // value * R, so the Montgomery multiplications cancel out
+
+    for coefficient in data.iter_mut() {
+        *coefficient = to_canonical(fqmul(*coefficient, value));
+    }
+}
+
+// Base case multiplication, (a_0 + a_1 X)(b_0 + b_1 X) mod (X^2 - gamma) for each pair of coefficients
+pub fn mult_portable(a: &mut [i16; 256], b: &[i16; 256]) {
+    for i in 0usize..128usize {
+        let (a_0, a_1) = (a[2*i], a[2*i + 1]);
+        let (b_0, b_1) = (b[2*i], b[2*i + 1]);
+
+        // fqmul(a_1, b_1) is a_1 * b_1 * R^-1, multiplying by gamma * R keeps it on the same R^-1 scale as the other products
+        let r_1 = fqmul(a_0, b_0) + fqmul(fqmul(a_1, b_1), ZETA_POWERS_MULT[i]);
+        let r_2 = fqmul(a_0, b_1) + fqmul(a_1, b_0);
+
+        // Both sums are in (-2q, 2q), multiplying by R^2 removes the factor R^-1
+        a[2*i] = to_canonical(fqmul(r_1, R2));
+        a[2*i + 1] = to_canonical(fqmul(r_2, R2));
+    }
+}
+
+pub fn ntt_portable(data: &mut [i16; 256]) {
+    let mut k = 1;
+    let mut len = 128;
+
+    while len >= 2 {
+        for block in data.chunks_exact_mut(2 * len) {
+            let zeta = ZETA_POWERS_NTT[k];
+
+            k += 1;
+
+            // Each of the 7 layers grows the coefficients by less than q, so they stay well inside i16 without reducing
+            let (low, high) = block.split_at_mut(len);
+            for (low, high) in low.iter_mut().zip(high.iter_mut()) {
+                let t = fqmul(zeta, *high);
+                *high = *low - t;
+                *low += t;
+            }
+        }
+
+        len /= 2;
+    }
+
+    for coefficient in data.iter_mut() {
+        *coefficient = to_canonical(barrett_reduce(*coefficient));
+    }
+}
+
+pub fn inverse_ntt_portable(data: &mut [i16; 256]) {
+    let mut k = 127;
+    let mut len = 2;
+    while len <= 128 {
+        for block in data.chunks_exact_mut(2 * len) {
+            let zeta = ZETA_POWERS_NTT[k];
+
+            k -= 1;
+
+            let (low, high) = block.split_at_mut(len);
+            for (low, high) in low.iter_mut().zip(high.iter_mut()) {
+                let t = *low;
+                *low = t + *high;
+                *high = fqmul(zeta, *high - t);
+            }
+        }
+
+        // Sums double each layer, starting under q they only need reducing once (after the third layer) to stay under 8q
+        if len == 8 {
+            for coefficient in data.iter_mut() {
+                *coefficient = barrett_reduce(*coefficient);
+            }
+        }
+
+        len *= 2;
+    }
+
+    scalar_mul(data, INV_128);
+}
+
 impl PartialEq for Ring {
     fn eq(&self, other: &Self) -> bool {
         for i in 0..256 {
@@ -185,129 +257,180 @@ impl PartialEq for Ring {
     }
 }
 
-//Tuple that is either all ring or all ntt, k is length
-#[derive(Clone, Debug)]
-pub struct Vector<const K: usize> {
-    pub data: [Ring; K]
+//Tuple that is either all ring or all ntt, its length is the parameter set's K
+// Adds a * b to result. One of the factors is usually a secret vector, so the product is wiped once it's added in
+fn add_product(result: &mut Ring, a: &Ring, b: &Ring) {
+    let mut product = Secret::new(a.clone());
+    product.mult(b);
+    result.add(&product);
 }
 
-impl<const K: usize> Vector<K> { 
-    pub fn new_ntt() -> Vector<K> {
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vector {
+    pub data: Vec<Ring>
+}
+
+impl Vector { 
+    pub fn new_ntt(k: usize) -> Vector {
         Vector {
-            data: core::array::from_fn(|_| Ring::ZEROES_NTT)
+            data: vec![Ring::ZEROES_NTT; k]
         }
     }
     
-    pub fn new_degree255() -> Vector<K> {
+    pub fn new_degree255(k: usize) -> Vector {
         Vector {
-            data: core::array::from_fn(|_| Ring::ZEROES_DEGREE255)
+            data: vec![Ring::ZEROES_DEGREE255; k]
         }
     }
 
-    pub fn add(&mut self, other: &Vector<K>) -> &mut Self {
-        for i in 0..K {
-            self.data[i].add(&other.data[i]);
+    pub fn add(&mut self, other: &Vector) -> &mut Self {
+        debug_assert_eq!(self.data.len(), other.data.len());
+
+        for (ring, other_ring) in self.data.iter_mut().zip(other.data.iter()) {
+            ring.add(other_ring);
         }
         self
     }
 
-    pub fn inner_product(mut self, other: Vector<K>) -> Ring {
+    pub fn inner_product(&self, other: &Vector) -> Ring {
+        debug_assert_eq!(self.data.len(), other.data.len());
+
         let mut result = match self.data[0].t {
             RingRepresentation::Degree255 => Ring::ZEROES_DEGREE255,
             RingRepresentation::NTT => Ring::ZEROES_NTT
         };
 
-        for i in 0..K {
-            result.add(
-                self.data[i].mult( &other.data[i] )
-            );
+        for (ring, other_ring) in self.data.iter().zip(other.data.iter()) {
+            add_product(&mut result, ring, other_ring);
         }
         result
     }   
 
     // NTT and NTT^-1 are in place and chainable
     pub fn ntt(mut self) -> Self {
-        for i in 0..K {
-            self.data[i].ntt();
+        self.ntt_in_place();
+        self
+    }
+
+    // For vectors that can't be moved out of, like wrapped secrets
+    pub fn ntt_in_place(&mut self) -> &mut Self {
+        for ring in self.data.iter_mut() {
+            ring.ntt();
         }
         self
     }
 
     pub fn inverse_ntt(mut self) -> Self {
-        for i in 0..K {
-            self.data[i].inverse_ntt();
+        for ring in self.data.iter_mut() {
+            ring.inverse_ntt();
         }
         self
     }
 }
 
-impl<const K: usize> PartialEq for Vector<K> {
-    fn eq(&self, other: &Self) -> bool {
-        for i in 0..K {
-            if self.data[i] != other.data[i] {
-                return false;
-            }
-        }
-        true
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Ring {
+    fn zeroize(&mut self) {
+        self.data.zeroize();
     }
 }
 
-// Represent a compressed ring or vector as its own type
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Vector {
+    fn zeroize(&mut self) {
+        self.data.iter_mut().for_each(zeroize::Zeroize::zeroize);
+    }
+}
 
+// Represent a compressed ring or vector as its own type, d is the number of bits each coefficient was compressed to
 #[derive(PartialEq, Clone, Debug)]
-pub struct Compressed<const D: usize, T> (pub T);
-
-pub trait CompressionConstants<const D: usize> {
-    const POW_HALF: u32;
-    const BITMASK: u16;
+pub struct Compressed<T> {
+    pub value: T,
+    pub d: usize
 }
 
-impl<const D: usize, T> CompressionConstants<D> for Compressed<D, T> {
-    const POW_HALF: u32 = 1 << (D - 1);
-    const BITMASK: u16 = (1 << D) - 1;
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> zeroize::Zeroize for Compressed<T> {
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
 }
 
 const Q_HALF: u32 = params::Q32 / 2;
 
-impl<const D: usize> Compressed<D, Ring> {
-    pub fn compress(mut ring: Ring) -> Compressed<D, Ring> {
-        for i in 0..256 {
-            ring.data[i] = ((((ring.data[i] as u32) << D) + Q_HALF) / params::Q32) as u16 & Compressed::<D, Ring>::BITMASK;
-        }
+// Compress_d and Decompress_d of a single coefficient
+fn compress_coefficient(x: i16, d: usize) -> i16 {
+    let bitmask = (1u32 << d) - 1;
+    (((((x as u32) << d) + Q_HALF) / params::Q32) & bitmask) as i16
+}
+
+fn decompress_coefficient(y: i16, d: usize) -> i16 {
+    let pow_half = 1u32 << (d - 1);
+    (((y as u32) * params::Q32 + pow_half) >> d) as i16
+}
+
+fn compress(data: &mut [i16; 256], d: usize) {
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: AVX2 support was just checked
+        return unsafe { avx2::compress(data, d) };
+    }
+
+    compress_portable(data, d)
+}
+
+fn decompress(data: &mut [i16; 256], d: usize) {
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: AVX2 support was just checked
+        return unsafe { avx2::decompress(data, d) };
+    }
+
+    decompress_portable(data, d)
+}
+
+pub fn compress_portable(data: &mut [i16; 256], d: usize) {
+    for coefficient in data.iter_mut() {
+        *coefficient = compress_coefficient(*coefficient, d);
+    }
+}
+
+pub fn decompress_portable(data: &mut [i16; 256], d: usize) {
+    for coefficient in data.iter_mut() {
+        *coefficient = decompress_coefficient(*coefficient, d);
+    }
+}
+
+impl Compressed<Ring> {
+    pub fn compress(mut ring: Ring, d: usize) -> Compressed<Ring> {
+        compress(&mut ring.data, d);
         
-        Compressed (ring)
+        Compressed { value: ring, d }
     }
 
     pub fn decompress(self) -> Ring {
-        let Compressed (mut ring) = self;
+        let Compressed { value: mut ring, d } = self;
 
-        for i in 0..256 {
-            ring.data[i] = (((ring.data[i] as u32) * params::Q32 + Compressed::<D, Ring>::POW_HALF) >> D) as u16;
-        }
+        decompress(&mut ring.data, d);
         
         ring
     }
 }
 
-impl<const D: usize, const K: usize> Compressed<D, Vector<K>> {
-    pub fn compress(mut vector: Vector<K>) -> Compressed<D, Vector<K>> {
-        for i in 0..K {
-            for j in 0..256 {
-                vector.data[i].data[j] = ((((vector.data[i].data[j] as u32) << D) + Q_HALF) / params::Q32) 
-                    as u16 & Compressed::<D, Vector<K>>::BITMASK;
-            }
+impl Compressed<Vector> {
+    pub fn compress(mut vector: Vector, d: usize) -> Compressed<Vector> {
+        for ring in vector.data.iter_mut() {
+            compress(&mut ring.data, d);
         }
         
-        Compressed (vector)
+        Compressed { value: vector, d }
     }
 
-    pub fn decompress(self) -> Vector<K> {
-        let Compressed (mut vector) = self;
+    pub fn decompress(self) -> Vector {
+        let Compressed { value: mut vector, d } = self;
 
-        for i in 0..K {
-            for j in 0..256 {
-                vector.data[i].data[j] = (((vector.data[i].data[j] as u32) * params::Q32 + Compressed::<D, Vector<K>>::POW_HALF) >> D) as u16;
-            }
+        for ring in vector.data.iter_mut() {
+            decompress(&mut ring.data, d);
         }
         
         vector
@@ -316,43 +439,189 @@ impl<const D: usize, const K: usize> Compressed<D, Vector<K>> {
 
 
 #[derive(Clone, Debug)]
-pub struct Matrix<const K: usize> {
-    pub data: [[Ring; K]; K]
+pub struct Matrix {
+    pub data: Vec<Vec<Ring>>
 }
 
-impl<const K: usize> Matrix<K> {
+impl Matrix {
 
-    pub fn new_ntt() -> Matrix<K> {
+    pub fn new_ntt(k: usize) -> Matrix {
         Matrix {
-            data: core::array::from_fn(|_| core::array::from_fn(|_| Ring::ZEROES_NTT))
+            data: vec![vec![Ring::ZEROES_NTT; k]; k]
         }
     }
 
-    // These matrix operations are only ever done once, so they can be consuming on the matrix
-    pub fn right_vector_multiply(mut self, vector: &Vector<K>) -> Vector<K> {
+    // The matrix can be cached across calls (see PreparedEncapsulationKey), so each entry is copied before being multiplied in place
+    pub fn right_vector_multiply(&self, vector: &Vector) -> Vector {
         debug_assert_eq!(self.data[0][0].t, RingRepresentation::NTT);
         debug_assert_eq!(vector.data[0].t, RingRepresentation::NTT);
 
-        let mut result = Vector::new_ntt();
+        let k = vector.data.len();
+        let mut result = Vector::new_ntt(k);
 
-        for i in 0..K {
-            for j in 0..K {
-                result.data[i].add(self.data[i][j].mult(&vector.data[j]));  
+        for i in 0..k {
+            for j in 0..k {
+                add_product(&mut result.data[i], &self.data[i][j], &vector.data[j]);
             }
         }
         result
     }
 
-    pub fn left_vector_multiply(mut self, vector: &Vector<K>) -> Vector<K> {
+    pub fn left_vector_multiply(&self, vector: &Vector) -> Vector {
         debug_assert_eq!(self.data[0][0].t, RingRepresentation::NTT);
         debug_assert_eq!(vector.data[0].t, RingRepresentation::NTT);
 
-        let mut result = Vector::new_ntt();
-        for i in 0..K {
-            for j in 0..K {
-                result.data[i].add(self.data[j][i].mult(&vector.data[j]));
+        let k = vector.data.len();
+        let mut result = Vector::new_ntt(k);
+
+        for i in 0..k {
+            for j in 0..k {
+                add_product(&mut result.data[i], &self.data[j][i], &vector.data[j]);
             }
         }
         result
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    // Deterministic coefficients in [0, q), from a simple LCG
+    fn test_ring(seed: u32) -> Ring {
+        let mut state = seed;
+        let mut ring = Ring::ZEROES_DEGREE255;
+        for coefficient in ring.data.iter_mut() {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            *coefficient = ((state >> 16) % params::Q32) as i16;
+        }
+        ring
+    }
+
+    // Multiplication in Z_q[X] / (X^256 + 1), the slow way
+    fn schoolbook_mult(a: &Ring, b: &Ring) -> [i16; 256] {
+        let mut result = [0i64; 256];
+        for i in 0..256 {
+            for j in 0..256 {
+                let product = a.data[i] as i64 * b.data[j] as i64;
+                match i + j < 256 {
+                    true => result[i + j] += product,
+                    false => result[i + j - 256] -= product
+                }
+            }
+        }
+        result.map(|coefficient| coefficient.rem_euclid(Q as i64) as i16)
+    }
+
+    #[test]
+    fn test_reductions() {
+        for a in i16::MIN..=i16::MAX {
+            let reduced = barrett_reduce(a);
+            assert!(reduced.abs() <= Q / 2);
+            assert_eq!((reduced as i32 - a as i32).rem_euclid(Q as i32), 0);
+        }
+
+        // R = 2^16 mod q
+        let r = (1i64 << 16) % Q as i64;
+        for a in (i16::MIN..=i16::MAX).step_by(7) {
+            for b in (i16::MIN..=i16::MAX).step_by(1009).chain([1, Q - 1, R2, INV_128, ZETA_POWERS_NTT[1], ZETA_POWERS_MULT[127]]) {
+                if (a as i32 * b as i32).abs() < Q as i32 * (1 << 15) {
+                    let reduced = fqmul(a, b);
+                    assert!(reduced.abs() < Q);
+                    assert_eq!((reduced as i64 * r - a as i64 * b as i64).rem_euclid(Q as i64), 0);
+                }
+            }
+        }
+
+        for a in -Q + 1..Q {
+            assert!((0..Q).contains(&to_canonical(a)));
+            assert_eq!((to_canonical(a) - a).rem_euclid(Q), 0);
+        }
+    }
+
+    #[test]
+    fn test_zeta_tables() {
+        for i in 0..128 {
+            let ntt = fastmodpow(params::ZETA, bitrev7(i as u8)) as i32;
+            let mult = fastmodpow(params::ZETA, 2 * bitrev7(i as u8) + 1) as i32;
+
+            // Multiplying by 1 takes them out of the Montgomery domain
+            assert_eq!(to_canonical(fqmul(ZETA_POWERS_NTT[i], 1)) as i32, ntt);
+            assert_eq!(to_canonical(fqmul(ZETA_POWERS_MULT[i], 1)) as i32, mult);
+        }
+    }
+
+    #[test]
+    fn test_ntt_roundtrip() {
+        for seed in 0..8 {
+            let ring = test_ring(seed);
+            let mut ntt = ring.clone();
+            ntt.ntt();
+            assert!(ntt.data.iter().all(|coefficient| (0..Q).contains(coefficient)));
+
+            ntt.inverse_ntt();
+            assert_eq!(ntt, ring);
+        }
+    }
+
+    #[test]
+    fn test_mult() {
+        for seed in 0..8 {
+            let (a, b) = (test_ring(2 * seed), test_ring(2 * seed + 1));
+            let expected = schoolbook_mult(&a, &b);
+
+            let (mut a_ntt, mut b_ntt) = (a.clone(), b.clone());
+            a_ntt.ntt();
+            b_ntt.ntt();
+            a_ntt.mult(&b_ntt).inverse_ntt();
+
+            assert_eq!(a_ntt.data, expected);
+        }
+
+        // The largest canonical coefficients, where the sums in the base case multiplication are furthest from 0
+        let mut max = Ring::ZEROES_NTT;
+        max.data = [Q - 1; 256];
+        let mut b = Ring::ZEROES_NTT;
+        b.data = core::array::from_fn(|i| if i % 2 == 0 { Q - 1 } else { 1 });
+        for other in [max.clone(), b] {
+            let mut product = max.clone();
+            product.mult(&other);
+            for i in 0..128 {
+                let (a_0, a_1, b_0, b_1) = (max.data[2*i] as i64, max.data[2*i + 1] as i64, other.data[2*i] as i64, other.data[2*i + 1] as i64);
+                let gamma = fastmodpow(params::ZETA, 2 * bitrev7(i as u8) + 1) as i64;
+                assert_eq!(product.data[2*i] as i64, (a_0 * b_0 + gamma * a_1 * b_1).rem_euclid(Q as i64));
+                assert_eq!(product.data[2*i + 1] as i64, (a_0 * b_1 + a_1 * b_0).rem_euclid(Q as i64));
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let (a, b) = (test_ring(0), test_ring(1));
+
+        let mut sum = a.clone();
+        sum.add(&b);
+        let mut difference = a.clone();
+        difference.sub(&b);
+
+        for i in 0..256 {
+            assert_eq!(sum.data[i], (a.data[i] as i32 + b.data[i] as i32).rem_euclid(Q as i32) as i16);
+            assert_eq!(difference.data[i], (a.data[i] - b.data[i]).rem_euclid(Q));
+        }
+
+        sum.sub(&b);
+        assert_eq!(sum, a);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let ring = test_ring(3);
+        let mut scaled = ring.clone();
+        scalar_mul(&mut scaled.data, 1234);
+
+        for i in 0..256 {
+            assert_eq!(scaled.data[i] as i32, ring.data[i] as i32 * 1234 % Q as i32);
+        }
+    }
+}
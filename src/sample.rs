@@ -1,17 +1,46 @@
 use crate::ring::*;
 use crate::params;
 use crate::crypt;
-
-use bitvec::prelude::*;
+use crate::secret::Secret;
+#[cfg(target_arch = "x86_64")]
+use crate::avx2;
 
 pub fn sample_ntt(mut xof_stream: crypt::XOF) -> Ring {
     let mut ring: Ring = Ring::ZEROES_NTT;
-    let mut three_bytes = [0u8; 3];
+    let mut block = [0u8; crypt::XOF_BLOCK];
     
     let mut j = 0;
     while j < 256 {
-        //Each iteration samples 3 unfiormly random bytes total
-        xof_stream.get_3_bytes(&mut three_bytes);
+        xof_stream.read_block(&mut block);
+        sample_ntt_block(&block, &mut ring, &mut j);
+    }
+
+    ring
+}
+
+// SampleNTT of 4 matrix entries at once, every stream is read until its ring is full
+pub fn sample_ntt_x4(mut xof_streams: crypt::XOFx4) -> [Ring; 4] {
+    let mut rings = [Ring::ZEROES_NTT, Ring::ZEROES_NTT, Ring::ZEROES_NTT, Ring::ZEROES_NTT];
+    let mut j = [0; 4];
+
+    while j.iter().any(|&j| j < 256) {
+        let blocks = xof_streams.read_blocks();
+
+        for ((block, ring), j) in blocks.iter().zip(rings.iter_mut()).zip(j.iter_mut()) {
+            sample_ntt_block(block, ring, j);
+        }
+    }
+
+    rings
+}
+
+// Fills the ring from j onwards with the coefficients a block of XOF output gives
+fn sample_ntt_block(block: &[u8; crypt::XOF_BLOCK], ring: &mut Ring, j: &mut usize) {
+    //Each iteration samples 3 unfiormly random bytes total
+    for three_bytes in block.chunks_exact(3) {
+        if *j == 256 {
+            break;
+        }
 
         let b1 = three_bytes[0] as u16;
         let b2 = three_bytes[1] as u16;
@@ -21,72 +50,109 @@ pub fn sample_ntt(mut xof_stream: crypt::XOF) -> Ring {
         let d2 = (b2 >> 4) | (b3 << 4); // Uniform random sample of 12 bits
 
         if d1 < params::Q {
-            ring.data[j] = d1;
-            j += 1;
+            ring.data[*j] = d1 as i16;
+            *j += 1;
         }
 
-        if d2 < params::Q && j < 256 {
-            ring.data[j] = d2;
-            j += 1;
+        if d2 < params::Q && *j < 256 {
+            ring.data[*j] = d2 as i16;
+            *j += 1;
         }
     }
-
-    ring
 }
 
-pub fn sample_poly_cbd<const ETA: usize>(byte_array: [u8; 64*ETA]) -> Ring 
+pub fn sample_poly_cbd(byte_array: Vec<u8>, eta: usize) -> Ring 
 {
-    let b = byte_array.view_bits::<Lsb0>();
-    let mut f: Ring = Ring::ZEROES_DEGREE255;
+    debug_assert_eq!(byte_array.len(), 64 * eta);
 
-    for i in 0..256 {
-        let mut x = 0u16;
-        let mut y = 0u16;
-
-        for j in 0..ETA {
-            x += b[i*2*ETA + j] as u16;
-            y += b[i*2*ETA + j + ETA] as u16;
-        }
+    // The PRF output is only ever used to sample secrets
+    let byte_array = Secret::new(byte_array);
+    let mut f: Ring = Ring::ZEROES_DEGREE255;
 
-        f.data[i] = (x + params::Q - y) % params::Q;
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: AVX2 support was just checked
+        unsafe { avx2::sample_poly_cbd(&byte_array, eta, &mut f.data) };
+        return f;
     }
 
+    sample_poly_cbd_portable(&byte_array, eta, &mut f.data);
+
     f
 }
 
-// Optimization Attempt
-// pub fn sample_poly_cbd_2(u32_random_array: [u32; 32]) -> Ring {
-//     const QI: i16 = params::Q as i16;
+pub fn sample_poly_cbd_portable(byte_array: &[u8], eta: usize, f: &mut [i16; 256]) {
+    match eta {
+        2 => cbd_2(byte_array, f),
+        3 => cbd_3(byte_array, f),
+        _ => panic!("Unsupported eta {}", eta)
+    }
+}
+
+// 4 bits (2 + 2) per coefficient, so every 32 bit word holds 8 coefficients
+fn cbd_2(byte_array: &[u8], f: &mut [i16; 256]) {
+    debug_assert_eq!(byte_array.len(), 128);
+
+    for (bytes, coefficients) in byte_array.chunks_exact(4).zip(f.chunks_exact_mut(8)) {
+        let word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+        // Sums of neighbouring bits, every 2 bit field is x or y of a coefficient
+        let sums = (word & 0x55555555) + ((word >> 1) & 0x55555555);
+
+        for (k, coefficient) in coefficients.iter_mut().enumerate() {
+            let x = (sums >> (4 * k)) & 0x3;
+            let y = (sums >> (4 * k + 2)) & 0x3;
+            *coefficient = to_canonical(x as i16 - y as i16);
+        }
+    }
+}
 
-//     let mut f: Ring = Ring::ZEROES_DEGREE255;
-//     let mut i = 0;
-//     for u32_random in u32_random_array.iter() {
-//         let odd_digits = u32_random & 0x55555555;
-//         let even_digits = u32_random & 0xAAAAAAAA;
+// 6 bits (3 + 3) per coefficient, so every 3 bytes hold 4 coefficients
+fn cbd_3(byte_array: &[u8], f: &mut [i16; 256]) {
+    debug_assert_eq!(byte_array.len(), 192);
 
-//         let sum_1 = odd_digits + (even_digits >> 1);
-        
-//         let x = sum_1 & 0x33333333;
-//         let y = ((((sum_1 >> 2) & 0x33333333) ^ 0x77777777) + 0x11111111) & 0x77777777;
+    for (bytes, coefficients) in byte_array.chunks_exact(3).zip(f.chunks_exact_mut(4)) {
+        let word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]);
 
-//         let mut sum_2 = x + y;
+        // Sums of 3 neighbouring bits, every 3 bit field is x or y of a coefficient
+        let sums = (word & 0x249249) + ((word >> 1) & 0x249249) + ((word >> 2) & 0x249249);
 
-//         for j in 0..8 {
-//             let c0 = (sum_2 & 0x07) as u8;
-//             let negative = (c0 & 0x04) == 0x04;
+        for (k, coefficient) in coefficients.iter_mut().enumerate() {
+            let x = (sums >> (6 * k)) & 0x7;
+            let y = (sums >> (6 * k + 3)) & 0x7;
+            *coefficient = to_canonical(x as i16 - y as i16);
+        }
+    }
+}
 
-//             let c1 = match negative {
-//                 false => c0 as i16, //positive
-//                 true => -(((c0 - 1) ^ 0x07) as i16), //negative
-//             };
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitvec::prelude::*;
 
-//             f.data[i + j] = (QI + c1) as u16 % params::Q;
+    // SamplePolyCBD as written in FIPS 203 (Algorithm 8), one bit at a time
+    fn sample_poly_cbd_reference(byte_array: &[u8], eta: usize) -> [i16; 256] {
+        let b = byte_array.view_bits::<Lsb0>();
 
-//             sum_2 >>= 4;
-//         }
+        core::array::from_fn(|i| {
+            let x: u16 = (0..eta).map(|j| b[i*2*eta + j] as u16).sum();
+            let y: u16 = (0..eta).map(|j| b[i*2*eta + j + eta] as u16).sum();
 
-//         i += 8;
-//     }
+            ((x + params::Q - y) % params::Q) as i16
+        })
+    }
 
-//     f
-// }
+    #[test]
+    fn test_sample_poly_cbd() {
+        for eta in [2, 3] {
+            let edges = [vec![0x00; 64 * eta], vec![0xFF; 64 * eta], vec![0x0F; 64 * eta], vec![0xF0; 64 * eta]];
+            let prf_outputs = (0..=255).map(|n| crypt::prf(&[n; 32], n, eta));
+
+            for bytes in edges.into_iter().chain(prf_outputs) {
+                let mut f = [0i16; 256];
+                sample_poly_cbd_portable(&bytes, eta, &mut f);
+                assert_eq!(f, sample_poly_cbd_reference(&bytes, eta), "eta = {}, {:?}", eta, bytes);
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Wiping of secret values, backed by the `zeroize` crate when the (default on) `zeroize` feature is enabled
+//!
+//! Without the feature every wipe is a no-op, so the rest of the crate doesn't need to be feature gated
+use std::ops::{Deref, DerefMut};
+
+/// Overwrites a value with zeroes in a way the compiler won't optimize out
+pub(crate) trait Wipe {
+    fn wipe(&mut self);
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize + ?Sized> Wipe for T {
+    fn wipe(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T: ?Sized> Wipe for T {
+    fn wipe(&mut self) {}
+}
+
+/// Wrapper for secret intermediates, wipes its contents when dropped
+pub(crate) struct Secret<T: Wipe>(T);
+
+impl<T: Wipe> Secret<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+impl<T: Wipe> Deref for Secret<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Wipe> DerefMut for Secret<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Wipe> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.wipe();
+    }
+}
+
+#[cfg(all(test, feature = "zeroize"))]
+mod tests {
+    use super::*;
+    use crate::ring::*;
+    use std::cell::Cell;
+
+    // Records when it gets wiped, so the wipe on drop can be seen after the value itself is gone
+    struct WipeFlag<'a>(&'a Cell<bool>);
+
+    impl zeroize::Zeroize for WipeFlag<'_> {
+        fn zeroize(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    #[test]
+    fn test_secret_drop() {
+        let wiped = Cell::new(false);
+
+        let secret = Secret::new(WipeFlag(&wiped));
+        assert!(!wiped.get());
+
+        drop(secret);
+        assert!(wiped.get());
+    }
+
+    #[test]
+    fn test_wipe() {
+        let mut bytes = [0xAAu8; 32];
+        bytes.wipe();
+        assert_eq!(bytes, [0u8; 32]);
+
+        let mut vector = Vector::new_ntt(2);
+        vector.data[1].data[7] = 1234;
+        vector.wipe();
+        assert!(vector.data.iter().all(|ring| ring.data == [0i16; 256]));
+    }
+}
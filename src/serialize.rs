@@ -3,185 +3,434 @@
 //! Find examples on how to serialize / deserialize in the repo's or crate's README.md
 use crate::ring::*;
 use crate::mlkem::*;
+use crate::params::*;
+use crate::error::Error;
 use bitvec::prelude::*;
-use bitvec::access::*;
+use std::borrow::Cow;
 
 pub type BitOrder = Lsb0;
 
-// Writes a wring into a BitSlice, assumes the ring is under modulo 2^D
-fn byte_encode<const D: usize>(f: &Ring, bitvec_slice: &mut BitSlice<BitSafeU8, BitOrder>) {
-    assert_eq!(bitvec_slice.len(), 256 * D);
+// (K, D_U, D_V) of every parameter set, used to recognize inputs meant for a different parameter set
+const PARAMETER_SETS: [(usize, usize, usize); 3] = [
+    (MlKem512::K, MlKem512::D_U, MlKem512::D_V),
+    (MlKem768::K, MlKem768::D_U, MlKem768::D_V),
+    (MlKem1024::K, MlKem1024::D_U, MlKem1024::D_V),
+];
+
+// Checks the input is exactly `expected` bytes long, `size` gives the length in bytes each parameter set would expect from its (K, D_U, D_V)
+fn check_length(bytes: &[u8], k: usize, expected: usize, size: fn(usize, usize, usize) -> usize) -> Result<(), Error> {
+    if bytes.len() == expected {
+        return Ok(());
+    }
+
+    match PARAMETER_SETS.iter().find(|&&(k, d_u, d_v)| bytes.len() == size(k, d_u, d_v)) {
+        Some(&(found, _, _)) => Err(Error::ParameterMismatch { expected: k, found }),
+        None => Err(Error::InvalidLength { expected, found: bytes.len() })
+    }
+}
+
+// The bytes behind a bit vector, which the decoders work on, only copied if the bits don't start on a byte boundary
+fn whole_bytes(bitvec: &BitVec<u8, BitOrder>) -> Result<Cow<'_, [u8]>, Error> {
+    if !bitvec.len().is_multiple_of(8) {
+        return Err(Error::InvalidBitLength { found: bitvec.len() });
+    }
+
+    match bitvec.as_raw_slice() {
+        bytes if 8 * bytes.len() == bitvec.len() => Ok(Cow::Borrowed(bytes)),
+        _ => Ok(Cow::Owned(bitvec.chunks(8).map(|byte| byte.load_le::<u8>()).collect()))
+    }
+}
+
+// Checks every coefficient of a decoded 12 bit ring is reduced modulo Q
+fn check_reduced(f: &Ring) -> Result<(), Error> {
+    match f.data.iter().all(|&coefficient| (coefficient as u16) < Q) {
+        true => Ok(()),
+        false => Err(Error::InvalidEncoding)
+    }
+}
 
-    for (slot, ele) in bitvec_slice.chunks_mut(D).zip(f.data.iter()) {
-        slot.store_le(*ele)
+// Writes a ring into 32 * D bytes, D bits per element, assumes the ring is under modulo 2^D
+fn byte_encode(f: &Ring, d: usize, bytes: &mut [u8]) {
+    debug_assert_eq!(bytes.len(), 32 * d);
+
+    match d {
+        1 => encode::<1>(f, bytes),
+        4 => encode::<4>(f, bytes),
+        5 => encode::<5>(f, bytes),
+        10 => encode::<10>(f, bytes),
+        11 => encode::<11>(f, bytes),
+        12 => encode::<12>(f, bytes),
+        _ => panic!("Unsupported d {}", d)
     }
 }
 
-// Reads a BitSlice into a Ring, D bits per element
-fn byte_decode<const D: usize>(bitvec_slice: &BitSlice<u8, BitOrder>, t: RingRepresentation) -> Ring {
+// 8 elements of D bits fill exactly D bytes, so they are packed into one word and written out at once
+fn encode<const D: usize>(f: &Ring, bytes: &mut [u8]) {
+    for (chunk, elements) in bytes.chunks_exact_mut(D).zip(f.data.chunks_exact(8)) {
+        let word = elements.iter().enumerate().fold(0u128, |word, (i, &ele)| word | (ele as u16 as u128) << (D * i));
+        chunk.copy_from_slice(&word.to_le_bytes()[..D]);
+    }
+}
+
+// Reads 32 * D bytes into a Ring, D bits per element, the length has to be checked by the caller
+fn byte_decode(bytes: &[u8], d: usize, t: RingRepresentation) -> Ring {
     let mut f = match t {
         RingRepresentation::NTT => Ring::ZEROES_NTT,
         RingRepresentation::Degree255 => Ring::ZEROES_DEGREE255
     };
 
-    assert_eq!(bitvec_slice.len(), 256 * D);
+    debug_assert_eq!(bytes.len(), 32 * d);
 
-    for (slot, ele) in bitvec_slice.chunks(D).zip(f.data.iter_mut()) {
-        *ele = slot.load_le();
+    match d {
+        1 => decode::<1>(bytes, &mut f),
+        4 => decode::<4>(bytes, &mut f),
+        5 => decode::<5>(bytes, &mut f),
+        10 => decode::<10>(bytes, &mut f),
+        11 => decode::<11>(bytes, &mut f),
+        12 => decode::<12>(bytes, &mut f),
+        _ => panic!("Unsupported d {}", d)
     }
     f
 }
 
+// The reverse of encode, D bytes are read into one word and split into 8 elements
+fn decode<const D: usize>(bytes: &[u8], f: &mut Ring) {
+    let mask = (1u128 << D) - 1;
+
+    for (chunk, elements) in bytes.chunks_exact(D).zip(f.data.chunks_exact_mut(8)) {
+        let mut word = [0u8; 16];
+        word[..D].copy_from_slice(chunk);
+        let word = u128::from_le_bytes(word);
+
+        for (i, ele) in elements.iter_mut().enumerate() {
+            *ele = ((word >> (D * i)) & mask) as i16;
+        }
+    }
+}
+
+/// Serialization to and from plain byte arrays, the array type comes from the MlKemParams trait (`PARAMS::EncapsulationKeyBytes`, `PARAMS::DecapsulationKeyBytes` or `PARAMS::CyphertextBytes`)
+pub trait MlKemBytes: Sized {
+    /// The byte array the value serializes into
+    type Bytes: ByteArray;
+
+    /// Serializes into a byte array
+    fn to_bytes(&self) -> Self::Bytes;
+
+    /// Deserializes from a byte array, returning an [Error] if it is not a valid encoding, decapsulation keys also have to pass the hash check of [check_decapsulation_key]
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error>;
+}
+
+// Copies a serialized value into its byte array type
+fn into_byte_array<B: ByteArray>(bitvec: BitVec<u8, BitOrder>) -> B {
+    let mut bytes = B::zeroed();
+    bytes.as_mut().copy_from_slice(bitvec.as_raw_slice());
+    bytes
+}
+
+impl<PARAMS: MlKemParams> MlKemBytes for EncapsulationKey<PARAMS> {
+    type Bytes = PARAMS::EncapsulationKeyBytes;
+
+    fn to_bytes(&self) -> Self::Bytes {
+        into_byte_array(self.serialize())
+    }
+
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        decode_encapsulation_key(bytes.as_ref(), PARAMS::K).map(EncapsulationKey::new)
+    }
+}
+
+impl<PARAMS: MlKemParams> MlKemBytes for DecapsulationKey<PARAMS> {
+    type Bytes = PARAMS::DecapsulationKeyBytes;
+
+    fn to_bytes(&self) -> Self::Bytes {
+        into_byte_array(self.serialize())
+    }
+
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        load_decapsulation_key_bytes(bytes.as_ref())
+    }
+}
+
+impl<PARAMS: MlKemParams> MlKemBytes for Ciphertext<PARAMS> {
+    type Bytes = PARAMS::CyphertextBytes;
+
+    fn to_bytes(&self) -> Self::Bytes {
+        into_byte_array(self.serialize())
+    }
+
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        decode_cyphertext(bytes.as_ref(), PARAMS::K, PARAMS::D_U, PARAMS::D_V).map(Ciphertext::new)
+    }
+}
+
+impl MlKemBytes for DecapsulationKeySeed {
+    type Bytes = [u8; DecapsulationKeySeed::SIZE];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        into_byte_array(self.serialize())
+    }
+
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        Self::try_deserialize(&bytes.as_ref().view_bits::<BitOrder>().to_bitvec())
+    }
+}
+
 pub trait MlKemSerialize {
     fn serialize(&self) -> BitVec<u8, BitOrder>;
 }
 
-pub trait MlKemDeserialize {
-    fn deserialize(bitvec: &BitVec<u8, BitOrder>) -> Self;
+pub trait MlKemDeserialize: Sized {
+    /// Deserializes the input, returning an [Error] if it has the wrong length or is not a valid encoding
+    fn try_deserialize(bitvec: &BitVec<u8, BitOrder>) -> Result<Self, Error>;
+
+    /// Same as [MlKemDeserialize::try_deserialize], but panics on malformed input, so should only be used on trusted input
+    fn deserialize(bitvec: &BitVec<u8, BitOrder>) -> Self {
+        match Self::try_deserialize(bitvec) {
+            Ok(value) => value,
+            Err(error) => panic!("Failed to deserialize: {}", error)
+        }
+    }
 }
 
-impl<const K: usize> MlKemSerialize for MlKemEncapsulationKey<{K}> {
+// The inner representations carry their own sizes, so serializing them needs no parameters
+
+impl MlKemSerialize for MlKemEncapsulationKey {
     fn serialize(&self) -> BitVec<u8, BitOrder> {
-        let mut bitvec = bitvec![u8, BitOrder; 0; 8 * (384 * K + 32)];
+        let mut bytes = vec![0u8; 384 * self.0.data.len() + 32];
+        encode_encapsulation_key(self, &mut bytes);
+        BitVec::from_vec(bytes)
+    }
+}
 
-        let (t_slice, rho_slice) = bitvec.split_at_mut(384 * K * 8);
+impl MlKemSerialize for MlKemDecapsulationKey {
+    fn serialize(&self) -> BitVec<u8, BitOrder> {
+        let k = self.0.data.len();
+        let mut bytes = vec![0u8; 768 * k + 96];
+        let (dk_pke_bytes, rest) = bytes.split_at_mut(384 * k);
+        let (ek_bytes, rest) = rest.split_at_mut(384 * k + 32);
+        let (hash_bytes, z_bytes) = rest.split_at_mut(32);
 
-        for (i, chunk) in t_slice.chunks_mut(384 * 8).enumerate() {
-            byte_encode::<12>(&self.0.data[i], chunk);
+        // Serialize dk_pke
+        for (ring, chunk) in self.0.data.iter().zip(dk_pke_bytes.chunks_exact_mut(384)) {
+            byte_encode(ring, 12, chunk);
         }
 
-        // Serialize rho into last 32 bytes
-        for (i, byte) in rho_slice.chunks_mut(8).enumerate() {
-            byte.store_le(self.1[i])
-        }
-        
-        bitvec
+        encode_encapsulation_key(&self.1, ek_bytes);
+        hash_bytes.copy_from_slice(&self.2);
+        // Implicit rejection randomness
+        z_bytes.copy_from_slice(&self.3);
+
+        BitVec::from_vec(bytes)
     }
 }
 
-impl<const K: usize> MlKemDeserialize for MlKemEncapsulationKey<{K}> {
-    fn deserialize(bitvec: &BitVec<u8, BitOrder>) -> Self {
-        let (t_slice, rho_slice) = bitvec.split_at(256 * 12 * K);
+impl MlKemSerialize for MlKemCyphertext {
+    fn serialize(&self) -> BitVec<u8, BitOrder> {
+        let (c1, c2) = self;
+        let k = c1.value.data.len();
 
-        let mut t: Vector<K> = Vector::new_ntt();
-        for (i, ring) in t_slice.chunks(256 * 12).enumerate() {
-            t.data[i] = byte_decode::<12>(ring, RingRepresentation::NTT);
-        }
+        let mut bytes = vec![0u8; 32 * (c1.d * k + c2.d)];
+        let (c1_bytes, c2_bytes) = bytes.split_at_mut(32 * c1.d * k);
 
-        let mut rho = [0u8; 32];
-        for (i, chunk) in rho_slice.chunks(8).enumerate() {
-            rho[i] = chunk.load_le();
+        for (ring, chunk) in c1.value.data.iter().zip(c1_bytes.chunks_exact_mut(32 * c1.d)) {
+            byte_encode(ring, c1.d, chunk);
         }
 
-        (t, rho)
+        byte_encode(&c2.value, c2.d, c2_bytes);
+
+        BitVec::from_vec(bytes)
     }
 }
 
-impl<const K: usize> MlKemSerialize for MlKemDecapsulationKey<{K}> {
+impl MlKemSerialize for Compressed<Ring> {
     fn serialize(&self) -> BitVec<u8, BitOrder> {
-        let mut bitvec = bitvec![u8, BitOrder; 0; 8*(768 * K + 96)];
-        let (dk_pke_slice, rest) = bitvec.split_at_mut(8*(384 * K));
-        let (ek_slice, rest) = rest.split_at_mut(8*(384 * K + 32));
-        let (hash_slice, z_slice) = rest.split_at_mut(8*32);
+        let mut bytes = vec![0u8; 32 * self.d];
+        byte_encode(&self.value, self.d, &mut bytes);
+        BitVec::from_vec(bytes)
+    }
+}
 
+// t, then rho in the last 32 bytes
+fn encode_encapsulation_key(ek: &MlKemEncapsulationKey, bytes: &mut [u8]) {
+    let (t_bytes, rho_bytes) = bytes.split_at_mut(384 * ek.0.data.len());
 
-        // Serialize dk_pke
-        for (i, chunk ) in dk_pke_slice.chunks_mut(8 * 384).enumerate() {
-            byte_encode::<12>(&self.0.data[i], chunk);
-        }
+    for (ring, chunk) in ek.0.data.iter().zip(t_bytes.chunks_exact_mut(384)) {
+        byte_encode(ring, 12, chunk);
+    }
 
-        // Use our ek serialize implementation to get the serialized ek
-        let mut serialized_ek  = self.1.serialize();
-        ek_slice.copy_from_bitslice(serialized_ek.split_at_mut(0).1);
+    rho_bytes.copy_from_slice(&ek.1);
+}
 
-        // Serialize hash
-        for (i, byte) in hash_slice.chunks_mut(8).enumerate() {
-            byte.store_le(self.2[i])
-        }
+// Deserializing them does need the parameter set's sizes, so they are decoded by functions the public types call
 
-        // Serialize implicit rejection randomness
-        for (i, byte) in z_slice.chunks_mut(8).enumerate() {
-            byte.store_le(self.3[i])
-        }
+pub(crate) fn decode_encapsulation_key(bytes: &[u8], k: usize) -> Result<MlKemEncapsulationKey, Error> {
+    check_length(bytes, k, 384 * k + 32, |k, _, _| 384 * k + 32)?;
 
+    let (t_bytes, rho_bytes) = bytes.split_at(384 * k);
 
-        bitvec
+    let mut t = Vector::new_ntt(k);
+    for (ring, chunk) in t.data.iter_mut().zip(t_bytes.chunks_exact(384)) {
+        *ring = byte_decode(chunk, 12, RingRepresentation::NTT);
     }
+
+    let rho: [u8; 32] = rho_bytes.try_into().unwrap();
+
+    // FIPS 203 modulus check
+    let ek = (t, rho);
+    modulus_check(&ek)?;
+
+    Ok(ek)
 }
 
-impl<const K: usize> MlKemDeserialize for MlKemDecapsulationKey<{K}> {
-    fn deserialize(bitvec: &BitVec<u8, BitOrder>) -> Self {
-        let (dk_pke_slice, rest) = bitvec.split_at(8*(384 * K));
-        let (ek_slice, rest) = rest.split_at(8*(384 * K + 32));
-        let (hash_slice, z_slice) = rest.split_at(8*(32));
+pub(crate) fn decode_decapsulation_key(bytes: &[u8], k: usize) -> Result<MlKemDecapsulationKey, Error> {
+    check_length(bytes, k, 768 * k + 96, |k, _, _| 768 * k + 96)?;
 
-        let mut dk_pke = Vector::new_ntt();
-        for (i, chunk) in dk_pke_slice.chunks(8*384).enumerate() {
-            dk_pke.data[i] = byte_decode::<12>(chunk, RingRepresentation::NTT);
-        }
+    let (dk_pke_bytes, rest) = bytes.split_at(384 * k);
+    let (ek_bytes, rest) = rest.split_at(384 * k + 32);
+    let (hash_bytes, z_bytes) = rest.split_at(32);
+
+    let mut dk_pke = Vector::new_ntt(k);
+    for (ring, chunk) in dk_pke.data.iter_mut().zip(dk_pke_bytes.chunks_exact(384)) {
+        *ring = byte_decode(chunk, 12, RingRepresentation::NTT);
+        check_reduced(ring)?;
+    }
 
-        let ek = MlKemEncapsulationKey::<{K}>::deserialize(&BitVec::from_bitslice(ek_slice));
+    let ek = decode_encapsulation_key(ek_bytes, k)?;
 
-        let mut hash = [0u8; 32];
-        for (i, byte) in hash_slice.chunks(8).enumerate() {
-            hash[i] = byte.load_le();
-        }
+    let hash: [u8; 32] = hash_bytes.try_into().unwrap();
+    let z: [u8; 32] = z_bytes.try_into().unwrap();
 
-        let mut z = [0u8; 32];
-        for (i, byte) in z_slice.chunks(8).enumerate() {
-            z[i] = byte.load_le();
-        }
+    Ok((dk_pke, ek, hash, z))
+}
 
-        (dk_pke, ek, hash, z)        
+pub(crate) fn decode_cyphertext(bytes: &[u8], k: usize, d_u: usize, d_v: usize) -> Result<MlKemCyphertext, Error> {
+    check_length(bytes, k, 32 * (d_u * k + d_v), |k, d_u, d_v| 32 * (d_u * k + d_v))?;
+
+    let (c1_bytes, c2_bytes) = bytes.split_at(32 * d_u * k);
+
+    let mut c1 = Vector::new_degree255(k);
+    for (ring, chunk) in c1.data.iter_mut().zip(c1_bytes.chunks_exact(32 * d_u)) {
+        *ring = byte_decode(chunk, d_u, RingRepresentation::Degree255);
     }
+
+    let c2 = byte_decode(c2_bytes, d_v, RingRepresentation::Degree255);
+
+    Ok((Compressed { value: c1, d: d_u }, Compressed { value: c2, d: d_v }))
 }
 
-impl<const K: usize, const D_U: usize, const D_V: usize> MlKemSerialize for MlKemCyphertext<{K}, {D_U}, {D_V}> where
-    [(); 32*(D_U * K + D_V)]:
-{
-    fn serialize(&self) -> BitVec<u8, BitOrder> {
-        let mut bitvec = bitvec![u8, BitOrder; 0; 256*(D_U * K + D_V)];
+// The message m of K-PKE, a ring compressed to 1 bit per coefficient
+pub(crate) fn decode_message(bytes: &[u8]) -> Result<Compressed<Ring>, Error> {
+    if bytes.len() != 32 {
+        return Err(Error::InvalidLength { expected: 32, found: bytes.len() });
+    }
+
+    let ring = byte_decode(bytes, 1, RingRepresentation::Degree255);
+    Ok(Compressed { value: ring, d: 1 })
+}
 
-        let (c1_slice, c2_slice) = bitvec.split_at_mut(256 * D_U * K);
+// The public key and cyphertext types forward to their inner representation
 
-        for (i, chunk) in c1_slice.chunks_mut(256 * D_U).enumerate() {
-            byte_encode::<{D_U}>(&self.0.0.data[i], chunk);
-        }
+impl<PARAMS: MlKemParams> MlKemSerialize for EncapsulationKey<PARAMS> {
+    fn serialize(&self) -> BitVec<u8, BitOrder> {
+        self.inner.serialize()
+    }
+}
 
-        byte_encode::<{D_V}>(&self.1.0, c2_slice);
+impl<PARAMS: MlKemParams> MlKemDeserialize for EncapsulationKey<PARAMS> {
+    fn try_deserialize(bitvec: &BitVec<u8, BitOrder>) -> Result<Self, Error> {
+        decode_encapsulation_key(&whole_bytes(bitvec)?, PARAMS::K).map(EncapsulationKey::new)
+    }
+}
 
-        bitvec
+impl<PARAMS: MlKemParams> MlKemSerialize for DecapsulationKey<PARAMS> {
+    fn serialize(&self) -> BitVec<u8, BitOrder> {
+        self.inner.serialize()
     }
 }
 
-impl<const K: usize, const D_U: usize, const D_V: usize> MlKemDeserialize for MlKemCyphertext<{K}, {D_U}, {D_V}> {
-    fn deserialize(bitvec: &BitVec<u8, BitOrder>) -> Self {
-        assert_eq!(bitvec.len(), 256 * (D_U * K + D_V));
+// Loading a decapsulation key runs every FIPS 203 section 7.3 input check, including the hash check
+impl<PARAMS: MlKemParams> MlKemDeserialize for DecapsulationKey<PARAMS> {
+    fn try_deserialize(bitvec: &BitVec<u8, BitOrder>) -> Result<Self, Error> {
+        load_decapsulation_key_bytes(&whole_bytes(bitvec)?)
+    }
+}
 
-        let (c1_slice, c2_slice) = bitvec.split_at(256 * D_U * K);
+// The seed form of a decapsulation key is just d || z, and has the same length for every parameter set
 
-        let mut c1 = Vector::new_degree255();
-        for (i, chunk) in c1_slice.chunks(256 * D_U).enumerate() {
-            c1.data[i] = byte_decode::<D_U>(chunk, RingRepresentation::Degree255);
-        }
+impl MlKemSerialize for DecapsulationKeySeed {
+    fn serialize(&self) -> BitVec<u8, BitOrder> {
+        BitVec::from_vec([*self.d(), *self.z()].concat())
+    }
+}
 
-        let c2 = byte_decode::<D_V>(c2_slice, RingRepresentation::Degree255);
+impl MlKemDeserialize for DecapsulationKeySeed {
+    fn try_deserialize(bitvec: &BitVec<u8, BitOrder>) -> Result<Self, Error> {
+        let bytes = whole_bytes(bitvec)?;
+        if bytes.len() != DecapsulationKeySeed::SIZE {
+            return Err(Error::InvalidLength { expected: DecapsulationKeySeed::SIZE, found: bytes.len() });
+        }
 
-        (Compressed::<D_U, Vector<K>>(c1), Compressed::<D_V, Ring>(c2))
+        Ok(DecapsulationKeySeed::new(bytes[..32].try_into().unwrap(), bytes[32..].try_into().unwrap()))
     }
 }
 
-impl MlKemSerialize for Compressed<1, Ring> {
+impl<PARAMS: MlKemParams> MlKemSerialize for Ciphertext<PARAMS> {
     fn serialize(&self) -> BitVec<u8, BitOrder> {
-        let mut bitvec = bitvec![u8, BitOrder; 0; 256];
-        byte_encode::<1>(&self.0, bitvec.chunks_mut(256).next().unwrap());
-        bitvec
+        self.inner.serialize()
     }
 }
 
-impl MlKemDeserialize for Compressed<1, Ring> {
-    fn deserialize(bitvec: &BitVec<u8, BitOrder>) -> Self {
-        let ring = byte_decode::<1>(&bitvec, RingRepresentation::Degree255);
-        Compressed(ring)
+impl<PARAMS: MlKemParams> MlKemDeserialize for Ciphertext<PARAMS> {
+    fn try_deserialize(bitvec: &BitVec<u8, BitOrder>) -> Result<Self, Error> {
+        decode_cyphertext(&whole_bytes(bitvec)?, PARAMS::K, PARAMS::D_U, PARAMS::D_V).map(Ciphertext::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::{OsRng, RngCore};
+
+    // ByteEncode as written in FIPS 203 (Algorithm 5), one element of D bits at a time
+    fn byte_encode_reference(f: &Ring, d: usize) -> Vec<u8> {
+        let mut bitvec = bitvec![u8, BitOrder; 0; 256 * d];
+        for (slot, ele) in bitvec.chunks_mut(d).zip(f.data.iter()) {
+            slot.store_le(*ele as u16);
+        }
+        bitvec.into_vec()
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_byte_encode_decode() {
+        for d in [1, 4, 5, 10, 11, 12] {
+            let modulus = if d == 12 { Q as u32 } else { 1 << d };
+
+            for _ in 0..100 {
+                let mut f = Ring::ZEROES_DEGREE255;
+                for ele in f.data.iter_mut() {
+                    *ele = (OsRng.next_u32() % modulus) as i16;
+                }
+
+                let mut bytes = vec![0u8; 32 * d];
+                byte_encode(&f, d, &mut bytes);
+                assert_eq!(bytes, byte_encode_reference(&f, d), "d = {}", d);
+                assert_eq!(byte_decode(&bytes, d, RingRepresentation::Degree255).data, f.data, "d = {}", d);
+            }
+        }
+    }
+
+    #[test]
+    fn test_whole_bytes() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let bitvec = bytes.view_bits::<BitOrder>().to_bitvec();
+        assert!(matches!(whole_bytes(&bitvec), Ok(Cow::Borrowed(b)) if b == bytes));
+
+        // Bits that don't start on a byte boundary are copied out
+        let shifted = bitvec![u8, BitOrder; 0; 8 * 257];
+        let mut shifted = shifted[3..3 + 8 * 256].to_bitvec();
+        shifted.copy_from_bitslice(&bitvec);
+        assert!(matches!(whole_bytes(&shifted), Ok(Cow::Owned(b)) if b == bytes));
+
+        assert_eq!(whole_bytes(&bitvec[..8 * 255 + 1].to_bitvec()).err(), Some(Error::InvalidBitLength { found: 8 * 255 + 1 }));
+    }
+}
@@ -19,7 +19,7 @@ pub fn fastmodpow(base: u16, exp: u8) -> u16 {
         if exp % 2 == 1 {
             result = (result * base) % Q32;
         }
-        exp = exp >> 1;
+        exp >>= 1;
         base = (base * base) % Q32;
     }
     result as u16
@@ -0,0 +1,211 @@
+//! Algorithm Variants
+//!
+//! - **[MlKemFinal]**: The published FIPS 203, this is what the 3 parameter sets run by default
+//! - **[MlKemIpd]**: The FIPS 203 initial public draft, KeyGen does not domain separate its seed with K
+//! - **[KyberR3]**: CRYSTALS-Kyber round 3, Encaps pre-hashes m and the cyphertext is hashed into the shared key
+//!
+//! A parameter set can be run under any variant by wrapping it in [WithVariant](crate::params::WithVariant), e.g. `WithVariant<MlKem768, KyberR3>` is Kyber768
+use crate::crypt;
+
+/// Trait for the steps that differ between the algorithm variants, every other step of KeyGen, Encaps and Decaps is shared.
+///
+/// The default implementations are those of the final FIPS 203
+pub trait Variant {
+    /// Expands the KeyGen seed `d` into `(rho, sigma)`, `k` is the parameter set's K
+    fn key_gen_seed(d: &[u8; 32], k: u8) -> ([u8; 32], [u8; 32]) {
+        let mut seed = [0u8; 33];
+        seed[..32].copy_from_slice(d);
+        seed[32] = k;
+
+        crypt::g::<33>(&seed)
+    }
+
+    /// Turns Encaps' random bytes into the message that gets encrypted
+    fn encaps_message(m: [u8; 32]) -> [u8; 32] {
+        m
+    }
+
+    /// Derives the shared key from the key produced by G, `c` lazily provides the serialized cyphertext
+    fn shared_key(key: [u8; 32], _c: impl FnOnce() -> Vec<u8>) -> [u8; 32] {
+        key
+    }
+
+    /// Derives the implicit rejection key from `z` and the serialized cyphertext
+    fn rejection_key(z: &[u8; 32], c: &[u8]) -> [u8; 32] {
+        crypt::j([z, c].concat())
+    }
+}
+
+/// ML-KEM as published in FIPS 203
+pub struct MlKemFinal;
+impl Variant for MlKemFinal {}
+
+/// ML-KEM as in the FIPS 203 initial public draft
+pub struct MlKemIpd;
+impl Variant for MlKemIpd {
+    fn key_gen_seed(d: &[u8; 32], _k: u8) -> ([u8; 32], [u8; 32]) {
+        crypt::g::<32>(d)
+    }
+}
+
+/// CRYSTALS-Kyber as submitted in round 3 of the NIST competition
+pub struct KyberR3;
+impl Variant for KyberR3 {
+    fn key_gen_seed(d: &[u8; 32], _k: u8) -> ([u8; 32], [u8; 32]) {
+        crypt::g::<32>(d)
+    }
+
+    // Kyber never releases the raw random bytes, only their hash
+    fn encaps_message(m: [u8; 32]) -> [u8; 32] {
+        crypt::h(&m.to_vec())
+    }
+
+    fn shared_key(key: [u8; 32], c: impl FnOnce() -> Vec<u8>) -> [u8; 32] {
+        crypt::j([key, crypt::h(&c())].concat())
+    }
+
+    fn rejection_key(z: &[u8; 32], c: &[u8]) -> [u8; 32] {
+        crypt::j([*z, crypt::h(&c.to_vec())].concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mlkem::*;
+    use crate::params::*;
+    use crate::serialize::*;
+
+    // Vectors generated by implementations that share no code with kemkem, all with the same seeds: RustCrypto's ml-kem
+    // 0.2.1 (FIPS 203) and 0.1.1 (FIPS 203 ipd), and pqc_kyber 0.7.1 (Kyber round 3). tools/refvectors regenerates them.
+    // Keys and cyphertexts are checked by their SHA3-256 hash, each array holds the vectors of MlKem512, MlKem768 and MlKem1024
+    const D: &str = "7AF65022E0A472ED6388638EA29D82DA68B4CF9FFDF2B67CD708EA5A370C6A7C";
+    const Z: &str = "B32D2AABD658245B07F4B3B91DDDCABECD95523A4DBAB3FF5B58FC1027D949D3";
+    const M: &str = "034FF14A56249C2521D4279EBA3D04931CC892BBC45002B5B33D9F0188ACBAF6";
+
+    struct VariantVector {
+        ek_hash: &'static str,
+        dk_hash: &'static str,
+        c_hash: &'static str,
+        key: &'static str,
+        // Decaps output after flipping the lowest bit of the cyphertext
+        rejection_key: &'static str
+    }
+
+    const FINAL: [VariantVector; 3] = [
+        VariantVector {
+            ek_hash: "6642C7A153F1703745162E6331472B519E6CF5340BBA8A02BC380BF8C54C8CD6",
+            dk_hash: "62F5DCBC16F4B7AF57F1EA0E1067B7A98958B5046A93DA27457E2486647AF5C5",
+            c_hash: "0B13224C2B8E4AFC29A477786458C39F2625FA52C1399D67EABC53D53F43720C",
+            key: "B2DAC7856CA45F0881513C04408AE1542F8EA231EB8D02CEC75673B6AFB32A64",
+            rejection_key: "B02486726D409970BAA1EC9F556B1936E0FED3103E131A84FD60A373892C5015"
+        },
+        VariantVector {
+            ek_hash: "0335FC8CC27A99316298F9936B13DB05C687FB1A8BD42ABA94CB38F52DAC739B",
+            dk_hash: "785645B8B1A81344133EB33F8A4DA66096AE508B1D73910D9315A4EB709DDAC9",
+            c_hash: "A61A4C410CAAF7E4B191A4659047046612FF4B86B5B853156F448BB00F3EDE78",
+            key: "4918DFB8C37E7F0F560A73F643FBDA59FA75E208C27E365D145E21F323BFB69F",
+            rejection_key: "9DCA9D327DF4747E62D42EBD8502EE99132824128DD0BCB3FEA6016CBA5121C5"
+        },
+        VariantVector {
+            ek_hash: "A9FDE45F0541405A83EC583892ADE24C4E196C175E9EE0485CADF4EAAF60C86E",
+            dk_hash: "CD8C198024FFDD0EE0429E0F1659135DEA99E9127F36BEF167BEE1A035082E67",
+            c_hash: "94349F48BC89F5BC57B80EE3AB039C94A6A1E01A64CBBC18F2BDD2B9986577E6",
+            key: "ADC0CE86A6AAFD8EAC9DF29FD19EDF634858ECAA113B57942D343AF77DBE2B90",
+            rejection_key: "D049FE6871DA8FED80DB4F65C6F473CF4A76D5621D823CFB3939BB715B002C4B"
+        },
+    ];
+
+    const IPD: [VariantVector; 3] = [
+        VariantVector {
+            ek_hash: "142CFF27558DA123E49F7BD1A5BF6FC8CADF625916ADDB001ED6249A36AF23B6",
+            dk_hash: "9E2974037DCEA5828FAF569F9507C35B0CC5C673DD75DE1691D5738D57FE02A8",
+            c_hash: "B66A75116981BD8A926DCCAEC657445FE22479B436F63373EA0589D3C10EBAF5",
+            key: "0DD902AA6A1F231386F871D775F84329C2BCFF341123DF0EBEF5B4036EA2FCE7",
+            rejection_key: "0B54584A12EDA191F23D3D140C4ABB4D3FF4CABEAA8131EAAB1A1DA31E251569"
+        },
+        VariantVector {
+            ek_hash: "4B06A79F0E9699DF2FDC9674EFBC9EDB9F99BEEA0352A2D69255ADF093C8CF32",
+            dk_hash: "24102AFEEADA0DD3D67D5D71B47CE5D5A4D60663E3A63959761056110770790A",
+            c_hash: "8E30DCE2556D6B7EEE12D956F787FBE93228084430AC9FBB5CADDC96F81E8232",
+            key: "5D408D2868678BCE0A0E8088337187F6B2DE16A96B4C2958F3740B3EE087B03F",
+            rejection_key: "62226CD15BDEB4DA46FC2558CF19E70A04610E37630F2132426267F436F24DA7"
+        },
+        VariantVector {
+            ek_hash: "E6E832F2498CA5A3431F40D3187B1ED965FDD6693B37F6EB408A99977AE49644",
+            dk_hash: "AF7617F6935B6AF440EA160BDA36C746CF7C7F37AB6C2156BDD7BE527E531B50",
+            c_hash: "731813A5C9FC3FB297A3FAF43D5D2477EBEDE28921099B841B3B37302833F548",
+            key: "03DE2ED4189DAEF8D8D0425718BD5332F5EEE0D39983735D1D611EF21C203AB7",
+            rejection_key: "2BF50863D13C2F0992AEAF11A4740D7E48EE9387A0B63E1FFC28EDCCF1F52435"
+        },
+    ];
+
+    const KYBER: [VariantVector; 3] = [
+        VariantVector {
+            ek_hash: "142CFF27558DA123E49F7BD1A5BF6FC8CADF625916ADDB001ED6249A36AF23B6",
+            dk_hash: "9E2974037DCEA5828FAF569F9507C35B0CC5C673DD75DE1691D5738D57FE02A8",
+            c_hash: "FF2C145E4308A95BA7C40D964932DB1F7013ADB8D2CF7ED9E1D43D27A6995BA0",
+            key: "B2A1C2F1F25470154310C417BBA24E7DF48871968547066D4B803A39949713F1",
+            rejection_key: "78F6516AE307DC037FDC264223B4977CA6E99E871CA8BC2CB599E57069FD3348"
+        },
+        VariantVector {
+            ek_hash: "4B06A79F0E9699DF2FDC9674EFBC9EDB9F99BEEA0352A2D69255ADF093C8CF32",
+            dk_hash: "24102AFEEADA0DD3D67D5D71B47CE5D5A4D60663E3A63959761056110770790A",
+            c_hash: "D8D21B6F60F937D239E2D77FD30C6E0343C08BE94AE55EB65FC8FF0C697E387B",
+            key: "5465E5548919929F480FB6A7979B1C425A736F102B3165CDF42906331253C55E",
+            rejection_key: "7BF1B7C710D366191B625B0CF1163B7D05EF1D1DFF8D2CA235B40F053C462DA3"
+        },
+        VariantVector {
+            ek_hash: "E6E832F2498CA5A3431F40D3187B1ED965FDD6693B37F6EB408A99977AE49644",
+            dk_hash: "AF7617F6935B6AF440EA160BDA36C746CF7C7F37AB6C2156BDD7BE527E531B50",
+            c_hash: "E600BC7FC1F3BED1FA3A07EAD20A7E7F944488458BA0B57082D859B039A125A2",
+            key: "4090BA4C1B124562BEE3F855E47D0A44B987362842F71C7C83FA4201A0F55980",
+            rejection_key: "C9ADDF1F6A710C42A54ADEF57BD9B48F9D82FFEF156158B14A9DBF7B5F08F3D6"
+        },
+    ];
+
+    fn from_hex(s: &str) -> [u8; 32] {
+        core::array::from_fn(|i| u8::from_str_radix(&s[2*i..2*i + 2], 16).unwrap())
+    }
+
+    fn check_variant<PARAMS: MlKemParams>(vector: &VariantVector) {
+        let (ek, dk) = key_gen_internal::<PARAMS>(from_hex(D), from_hex(Z));
+
+        assert_eq!(crypt::h(&ek.serialize().into_vec()), from_hex(vector.ek_hash));
+        assert_eq!(crypt::h(&dk.serialize().into_vec()), from_hex(vector.dk_hash));
+
+        let (key, c) = encaps_internal::<PARAMS>(ek, from_hex(M));
+
+        assert_eq!(crypt::h(&c.serialize().into_vec()), from_hex(vector.c_hash));
+        assert_eq!(*key.as_bytes(), from_hex(vector.key));
+        assert_eq!(*decaps::<PARAMS>(c.clone(), dk.clone()).as_bytes(), from_hex(vector.key));
+
+        let mut c_bits = c.serialize();
+        let flipped = !c_bits[0];
+        c_bits.set(0, flipped);
+        let c = Ciphertext::<PARAMS>::deserialize(&c_bits);
+
+        assert_eq!(*decaps::<PARAMS>(c, dk).as_bytes(), from_hex(vector.rejection_key));
+    }
+
+    #[test]
+    fn mlkem_final() {
+        check_variant::<MlKem512>(&FINAL[0]);
+        check_variant::<MlKem768>(&FINAL[1]);
+        check_variant::<MlKem1024>(&FINAL[2]);
+    }
+
+    #[test]
+    fn mlkem_ipd() {
+        check_variant::<WithVariant<MlKem512, MlKemIpd>>(&IPD[0]);
+        check_variant::<WithVariant<MlKem768, MlKemIpd>>(&IPD[1]);
+        check_variant::<WithVariant<MlKem1024, MlKemIpd>>(&IPD[2]);
+    }
+
+    #[test]
+    fn kyber_r3() {
+        check_variant::<WithVariant<MlKem512, KyberR3>>(&KYBER[0]);
+        check_variant::<WithVariant<MlKem768, KyberR3>>(&KYBER[1]);
+        check_variant::<WithVariant<MlKem1024, KyberR3>>(&KYBER[2]);
+    }
+}
@@ -0,0 +1,147 @@
+//! NIST ACVP test vectors, see `tests/data/acvp/README.md` for where they come from
+//!
+//! Every case of every `.json` file in the keyGen and encapDecap directories is run through the deterministic internals, for the parameter set named by its test group.
+//! The key check groups in `tests/data/keycheck` are in the same format but self-authored, they are run by their own test
+use std::fs;
+use std::path::Path;
+
+use bitvec::{vec::BitVec, view::BitView};
+use serde::Deserialize;
+
+use kemkem::{mlkem::*, params::*, serialize::*};
+
+const DATA_DIR: &str = "tests/data";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VectorSet {
+    mode: String,
+    test_groups: Vec<TestGroup>,
+}
+
+// Fields are optional as each mode and function only uses some of them
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestGroup {
+    tg_id: u32,
+    parameter_set: String,
+    function: Option<String>,
+    dk: Option<String>,
+    tests: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestCase {
+    tc_id: u32,
+    d: Option<String>,
+    z: Option<String>,
+    m: Option<String>,
+    ek: Option<String>,
+    dk: Option<String>,
+    c: Option<String>,
+    k: Option<String>,
+    test_passed: Option<bool>,
+}
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+fn to_array(field: &Option<String>) -> [u8; 32] {
+    from_hex(field.as_ref().unwrap()).try_into().unwrap()
+}
+
+fn to_bits(field: &Option<String>) -> BitVec<u8, BitOrder> {
+    from_hex(field.as_ref().unwrap()).view_bits::<BitOrder>().to_bitvec()
+}
+
+fn key_gen_case<PARAMS: MlKemParams>(test: &TestCase) {
+    let (ek, dk) = key_gen_internal::<PARAMS>(to_array(&test.d), to_array(&test.z));
+
+    assert_eq!(ek.serialize(), to_bits(&test.ek), "tcId {}: ek", test.tc_id);
+    assert_eq!(dk.serialize(), to_bits(&test.dk), "tcId {}: dk", test.tc_id);
+}
+
+fn encapsulation_case<PARAMS: MlKemParams>(test: &TestCase) {
+    let ek = EncapsulationKey::<PARAMS>::try_deserialize(&to_bits(&test.ek)).unwrap();
+    let (key, c) = encaps_internal::<PARAMS>(ek, to_array(&test.m));
+
+    assert_eq!(c.serialize(), to_bits(&test.c), "tcId {}: c", test.tc_id);
+    assert_eq!(*key.as_bytes(), to_array(&test.k), "tcId {}: k", test.tc_id);
+}
+
+// The decapsulation key is shared by the whole group
+fn decapsulation_case<PARAMS: MlKemParams>(dk: &Option<String>, test: &TestCase) {
+    let dk = load_decapsulation_key::<PARAMS>(&to_bits(dk)).unwrap();
+    let c = Ciphertext::<PARAMS>::try_deserialize(&to_bits(&test.c)).unwrap();
+
+    assert_eq!(*decaps::<PARAMS>(c, dk).as_bytes(), to_array(&test.k), "tcId {}: k", test.tc_id);
+}
+
+fn encapsulation_key_check_case<PARAMS: MlKemParams>(test: &TestCase) {
+    let result = EncapsulationKey::<PARAMS>::try_deserialize(&to_bits(&test.ek));
+
+    assert_eq!(result.is_ok(), test.test_passed.unwrap(), "tcId {}: {:?}", test.tc_id, result.err());
+}
+
+fn decapsulation_key_check_case<PARAMS: MlKemParams>(test: &TestCase) {
+    let result = load_decapsulation_key::<PARAMS>(&to_bits(&test.dk));
+
+    assert_eq!(result.is_ok(), test.test_passed.unwrap(), "tcId {}: {:?}", test.tc_id, result.err());
+}
+
+fn run_group<PARAMS: MlKemParams>(mode: &str, group: &TestGroup) {
+    for test in &group.tests {
+        match (mode, group.function.as_deref()) {
+            ("keyGen", _) => key_gen_case::<PARAMS>(test),
+            ("encapDecap", Some("encapsulation")) => encapsulation_case::<PARAMS>(test),
+            ("encapDecap", Some("decapsulation")) => decapsulation_case::<PARAMS>(&group.dk, test),
+            ("encapDecap", Some("encapsulationKeyCheck")) => encapsulation_key_check_case::<PARAMS>(test),
+            ("encapDecap", Some("decapsulationKeyCheck")) => decapsulation_key_check_case::<PARAMS>(test),
+            (mode, function) => panic!("tgId {}: unknown test group {} / {:?}", group.tg_id, mode, function)
+        }
+    }
+}
+
+// Runs every vector file in the directory, returning how many test cases were run
+fn run_directory(directory: &str) -> usize {
+    let mut cases = 0;
+
+    for entry in fs::read_dir(Path::new(DATA_DIR).join(directory)).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension() != Some("json".as_ref()) {
+            continue;
+        }
+
+        let vector_set: VectorSet = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+
+        for group in &vector_set.test_groups {
+            match group.parameter_set.as_str() {
+                "ML-KEM-512" => run_group::<MlKem512>(&vector_set.mode, group),
+                "ML-KEM-768" => run_group::<MlKem768>(&vector_set.mode, group),
+                "ML-KEM-1024" => run_group::<MlKem1024>(&vector_set.mode, group),
+                parameter_set => panic!("{}: unknown parameter set {}", path.display(), parameter_set)
+            }
+            cases += group.tests.len();
+        }
+    }
+
+    cases
+}
+
+#[test]
+fn acvp_key_gen() {
+    assert!(run_directory("acvp/ML-KEM-keyGen-FIPS203") > 0);
+}
+
+#[test]
+fn acvp_encap_decap() {
+    assert!(run_directory("acvp/ML-KEM-encapDecap-FIPS203") > 0);
+}
+
+// Not NIST vectors, see `tests/data/keycheck/README.md`
+#[test]
+fn self_authored_key_check() {
+    assert!(run_directory("keycheck") > 0);
+}
@@ -0,0 +1,194 @@
+//! NIST style known-answer tests, see `tests/data/kat/README.md` for where they come from
+//!
+//! The `.rsp` files are driven by the AES-256-CTR DRBG of the NIST `PQCgenKAT_kem` driver: a DRBG seeded with `00 01 .. 2F` gives each count's 48 byte seed, then a DRBG seeded with that gives the 64 bytes of `d || z` for KeyGen and the 32 bytes of `m` for Encaps
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::Aes256;
+use sha2::{Digest, Sha256};
+
+use kemkem::{mlkem::*, params::*, serialize::*};
+
+const DATA_DIR: &str = "tests/data/kat";
+
+// SHA-256 of a single count (no header) as written by PQClean's `nistkat` test, published as `nistkat-sha256` in each scheme's META.yml
+const PQCLEAN_512: &str = "c70041a761e01cd6426fa60e9fd6a4412c2be817386c8d0f3334898082512782";
+const PQCLEAN_768: &str = "5352539586b6c3df58be6158a6250aeff402bd73060b0a3de68850ac074c17c3";
+const PQCLEAN_1024: &str = "f580d851e5fb27e6876e5e203fa18be4cdbfd49e05d48fec3d3992c8f43a13e6";
+
+/// AES-256-CTR DRBG as implemented by NIST's `rng.c` (no derivation function, no reseeding)
+struct KatDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl KatDrbg {
+    fn new(entropy_input: &[u8; 48]) -> Self {
+        let mut drbg = KatDrbg { key: [0u8; 32], v: [0u8; 16] };
+        drbg.update(Some(entropy_input));
+        drbg
+    }
+
+    // Increments V as a big endian counter, then encrypts it
+    fn next_block(&mut self) -> [u8; 16] {
+        for byte in self.v.iter_mut().rev() {
+            *byte = byte.wrapping_add(1);
+            if *byte != 0 {
+                break;
+            }
+        }
+
+        let mut block = self.v.into();
+        Aes256::new(&self.key.into()).encrypt_block(&mut block);
+        block.into()
+    }
+
+    fn update(&mut self, provided_data: Option<&[u8; 48]>) {
+        let mut temp = [0u8; 48];
+        for chunk in temp.chunks_mut(16) {
+            chunk.copy_from_slice(&self.next_block());
+        }
+
+        if let Some(provided_data) = provided_data {
+            temp.iter_mut().zip(provided_data).for_each(|(t, p)| *t ^= p);
+        }
+
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+
+    // NIST's randombytes, every call ends with an update so splitting a request changes the output
+    fn random_bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut output = [0u8; N];
+        for chunk in output.chunks_mut(16) {
+            let block = self.next_block();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+
+        self.update(None);
+        output
+    }
+}
+
+/// One count of a `.rsp` file
+struct KatEntry {
+    count: usize,
+    seed: [u8; 48],
+    pk: Vec<u8>,
+    sk: Vec<u8>,
+    ct: Vec<u8>,
+    ss: [u8; 32],
+}
+
+impl KatEntry {
+    fn generate<PARAMS: MlKemParams>(count: usize, seed: [u8; 48]) -> Self {
+        let mut drbg = KatDrbg::new(&seed);
+
+        let coins = drbg.random_bytes::<64>();
+        let d = coins[..32].try_into().unwrap();
+        let z = coins[32..].try_into().unwrap();
+        let (ek, dk) = key_gen_internal::<PARAMS>(d, z);
+
+        let m = drbg.random_bytes::<32>();
+        let (key, c) = encaps_internal::<PARAMS>(ek.clone(), m);
+
+        assert_eq!(decaps::<PARAMS>(c.clone(), dk.clone()), key, "count {}: decaps", count);
+
+        KatEntry {
+            count,
+            seed,
+            pk: ek.serialize().into_vec(),
+            sk: dk.serialize().into_vec(),
+            ct: c.serialize().into_vec(),
+            ss: *key.as_bytes(),
+        }
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+impl fmt::Display for KatEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "count = {}", self.count)?;
+        writeln!(f, "seed = {}", to_hex(&self.seed))?;
+        writeln!(f, "pk = {}", to_hex(&self.pk))?;
+        writeln!(f, "sk = {}", to_hex(&self.sk))?;
+        writeln!(f, "ct = {}", to_hex(&self.ct))?;
+        writeln!(f, "ss = {}", to_hex(&self.ss))
+    }
+}
+
+// The per count seeds, drawn from a DRBG seeded with 00 01 .. 2F
+fn kat_seeds(counts: usize) -> Vec<[u8; 48]> {
+    let mut drbg = KatDrbg::new(&core::array::from_fn(|i| i as u8));
+
+    (0..counts).map(|_| drbg.random_bytes::<48>()).collect()
+}
+
+fn sha256_hex(s: &str) -> String {
+    Sha256::digest(s.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// Regenerates every count of a .rsp file from its seed, and compares each value
+fn check_rsp<PARAMS: MlKemParams>(entries: &[HashMap<&str, &str>]) -> usize {
+    for fields in entries {
+        let count = fields["count"].parse().unwrap();
+        let seed = from_hex(fields["seed"]).try_into().unwrap();
+        let entry = KatEntry::generate::<PARAMS>(count, seed);
+
+        assert_eq!(to_hex(&entry.pk), fields["pk"], "count {}: pk", count);
+        assert_eq!(to_hex(&entry.sk), fields["sk"], "count {}: sk", count);
+        assert_eq!(to_hex(&entry.ct), fields["ct"], "count {}: ct", count);
+        assert_eq!(to_hex(&entry.ss), fields["ss"], "count {}: ss", count);
+    }
+
+    entries.len()
+}
+
+#[test]
+fn kat_rsp_files() {
+    let mut counts = 0;
+
+    for entry in fs::read_dir(DATA_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension() != Some("rsp".as_ref()) {
+            continue;
+        }
+
+        let rsp = fs::read_to_string(&path).unwrap();
+        let (header, body) = rsp.split_once("\n\n").unwrap();
+
+        // Each count is a block of `name = value` lines
+        let entries: Vec<HashMap<&str, &str>> = body
+            .split("\n\n")
+            .filter(|block| !block.trim().is_empty())
+            .map(|block| block.lines().filter_map(|line| line.split_once(" = ")).collect())
+            .collect();
+
+        counts += match header.trim_start_matches("# ") {
+            "ML-KEM-512" => check_rsp::<MlKem512>(&entries),
+            "ML-KEM-768" => check_rsp::<MlKem768>(&entries),
+            "ML-KEM-1024" => check_rsp::<MlKem1024>(&entries),
+            name => panic!("{}: unknown parameter set {}", path.display(), name)
+        };
+    }
+
+    assert!(counts > 0);
+}
+
+#[test]
+fn kat_pqclean_hashes() {
+    let seed = kat_seeds(1)[0];
+
+    assert_eq!(sha256_hex(&KatEntry::generate::<MlKem512>(0, seed).to_string()), PQCLEAN_512);
+    assert_eq!(sha256_hex(&KatEntry::generate::<MlKem768>(0, seed).to_string()), PQCLEAN_768);
+    assert_eq!(sha256_hex(&KatEntry::generate::<MlKem1024>(0, seed).to_string()), PQCLEAN_1024);
+}
@@ -0,0 +1,205 @@
+//! Prints the vectors hard coded in `src/variant.rs` and `src/rejection_test.rs`, computed with implementations
+//! that share no code with kemkem. The output is in the same layout as those files, so it can be diffed against them
+use kem::Decapsulate;
+use sha3::digest::{ExtendableOutput, Update};
+use sha3::{Digest, Sha3_256, Shake256};
+
+const D: &str = "7AF65022E0A472ED6388638EA29D82DA68B4CF9FFDF2B67CD708EA5A370C6A7C";
+const Z: &str = "B32D2AABD658245B07F4B3B91DDDCABECD95523A4DBAB3FF5B58FC1027D949D3";
+const M: &str = "034FF14A56249C2521D4279EBA3D04931CC892BBC45002B5B33D9F0188ACBAF6";
+
+// KeyGen_internal, Encaps_internal and Decaps of one implementation and parameter set, on serialized keys and cyphertexts
+trait Reference {
+    const D_U: usize;
+    const K: usize;
+
+    // Returns (ek, dk)
+    fn key_gen(d: &[u8; 32], z: &[u8; 32]) -> (Vec<u8>, Vec<u8>);
+    // Returns (key, c)
+    fn encaps(ek: &[u8], m: &[u8; 32]) -> ([u8; 32], Vec<u8>);
+    fn decaps(dk: &[u8], c: &[u8]) -> [u8; 32];
+}
+
+// RustCrypto's ml-kem, 0.2.1 is FIPS 203 and 0.1.1 the initial public draft, they have the same API
+macro_rules! rustcrypto {
+    ($name:ident, $krate:ident, $params:ident, $k:expr, $d_u:expr) => {
+        struct $name;
+        impl Reference for $name {
+            const D_U: usize = $d_u;
+            const K: usize = $k;
+
+            fn key_gen(d: &[u8; 32], z: &[u8; 32]) -> (Vec<u8>, Vec<u8>) {
+                use $krate::{EncodedSizeUser, KemCore};
+                let (dk, ek) = $krate::$params::generate_deterministic(&(*d).into(), &(*z).into());
+                (ek.as_bytes().to_vec(), dk.as_bytes().to_vec())
+            }
+
+            fn encaps(ek: &[u8], m: &[u8; 32]) -> ([u8; 32], Vec<u8>) {
+                use $krate::{EncapsulateDeterministic, EncodedSizeUser, KemCore};
+                type Ek = <$krate::$params as KemCore>::EncapsulationKey;
+                let ek = Ek::from_bytes(&$krate::Encoded::<Ek>::try_from(ek).unwrap());
+                let (c, key) = ek.encapsulate_deterministic(&(*m).into()).unwrap();
+                (key.into(), c.to_vec())
+            }
+
+            fn decaps(dk: &[u8], c: &[u8]) -> [u8; 32] {
+                use $krate::{EncodedSizeUser, KemCore};
+                type Dk = <$krate::$params as KemCore>::DecapsulationKey;
+                let dk = Dk::from_bytes(&$krate::Encoded::<Dk>::try_from(dk).unwrap());
+                dk.decapsulate(&$krate::Ciphertext::<$krate::$params>::try_from(c).unwrap()).unwrap().into()
+            }
+        }
+    };
+}
+
+// Hands pqc_kyber's Encaps the 32 bytes of m as its randomness, which it hashes before use like Kyber's Encaps does
+struct FixedRng([u8; 32]);
+
+impl rand_core::RngCore for FixedRng {
+    fn next_u32(&mut self) -> u32 {
+        unimplemented!()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unimplemented!()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.copy_from_slice(&self.0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl rand_core::CryptoRng for FixedRng {}
+
+// pqc_kyber, the Kyber round 3 reference implementation ported to Rust, for the parameter set it was built with
+struct Kyber;
+impl Reference for Kyber {
+    const D_U: usize = if pqc_kyber::KYBER_K == 4 { 11 } else { 10 };
+    const K: usize = pqc_kyber::KYBER_K;
+
+    fn key_gen(d: &[u8; 32], z: &[u8; 32]) -> (Vec<u8>, Vec<u8>) {
+        let key_pair = pqc_kyber::derive(&[d.as_slice(), z].concat()).unwrap();
+        (key_pair.public.to_vec(), key_pair.secret.to_vec())
+    }
+
+    fn encaps(ek: &[u8], m: &[u8; 32]) -> ([u8; 32], Vec<u8>) {
+        let (c, key) = pqc_kyber::encapsulate(ek, &mut FixedRng(*m)).unwrap();
+        (key, c.to_vec())
+    }
+
+    fn decaps(dk: &[u8], c: &[u8]) -> [u8; 32] {
+        pqc_kyber::decapsulate(c, dk).unwrap()
+    }
+}
+
+rustcrypto!(Final512, ml_kem, MlKem512, 2, 10);
+rustcrypto!(Final768, ml_kem, MlKem768, 3, 10);
+rustcrypto!(Final1024, ml_kem, MlKem1024, 4, 11);
+rustcrypto!(Ipd512, ml_kem_ipd, MlKem512, 2, 10);
+rustcrypto!(Ipd768, ml_kem_ipd, MlKem768, 3, 10);
+rustcrypto!(Ipd1024, ml_kem_ipd, MlKem1024, 4, 11);
+
+fn from_hex(s: &str) -> [u8; 32] {
+    core::array::from_fn(|i| u8::from_str_radix(&s[2*i..2*i + 2], 16).unwrap())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+fn h(bytes: &[u8]) -> String {
+    hex(&Sha3_256::digest(bytes))
+}
+
+// J(z || c) of FIPS 203, the key Decaps returns when it rejects c
+fn j(z: &[u8; 32], c: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    Shake256::default().chain([z.as_slice(), c].concat()).finalize_xof_into(&mut key);
+    key
+}
+
+// One `VariantVector` of src/variant.rs
+fn variant_vector<R: Reference>() {
+    let (ek, dk) = R::key_gen(&from_hex(D), &from_hex(Z));
+    let (key, c) = R::encaps(&ek, &from_hex(M));
+    assert_eq!(R::decaps(&dk, &c), key);
+
+    let mut flipped = c.clone();
+    flipped[0] ^= 0x01;
+
+    println!("        VariantVector {{");
+    println!("            ek_hash: \"{}\",", h(&ek));
+    println!("            dk_hash: \"{}\",", h(&dk));
+    println!("            c_hash: \"{}\",", h(&c));
+    println!("            key: \"{}\",", hex(&key));
+    println!("            rejection_key: \"{}\"", hex(&R::decaps(&dk, &flipped)));
+    println!("        }},");
+}
+
+fn variant_vectors<R512: Reference, R768: Reference, R1024: Reference>(name: &str) {
+    println!("    const {}: [VariantVector; 3] = [", name);
+    variant_vector::<R512>();
+    variant_vector::<R768>();
+    variant_vector::<R1024>();
+    println!("    ];");
+    println!();
+}
+
+// The `RejectionVector`s of src/rejection_test.rs for one parameter set, with the same modifications as its `modify`
+fn rejection_vectors<R: Reference>(name: &str) {
+    let (ek, dk) = R::key_gen(&from_hex(D), &from_hex(Z));
+    let (key, c) = R::encaps(&ek, &from_hex(M));
+
+    type Modify = fn(&mut Vec<u8>);
+    let modifications: [(&str, Modify); 7] = [
+        ("None", |_| {}),
+        ("UFirstBit", |c| c[0] ^= 0x01),
+        ("ULastBit", |c| c[32 * R::D_U * R::K - 1] ^= 0x80),
+        ("VFirstBit", |c| c[32 * R::D_U * R::K] ^= 0x01),
+        ("VLastBit", |c| *c.last_mut().unwrap() ^= 0x80),
+        ("AllZero", |c| c.fill(0x00)),
+        ("AllMax", |c| c.fill(0xFF)),
+    ];
+
+    println!("const {}: [RejectionVector; 7] = [", name);
+    for (modification, modify) in modifications {
+        let mut modified = c.clone();
+        modify(&mut modified);
+
+        let decapsulated = R::decaps(&dk, &modified);
+        let rejected = decapsulated == j(&from_hex(Z), &modified);
+        assert_eq!(rejected, decapsulated != key, "{}", modification);
+
+        println!("    RejectionVector {{ modification: Modification::{}, key: \"{}\", rejected: {} }},", modification, hex(&decapsulated), rejected);
+    }
+    println!("];");
+    println!();
+}
+
+fn main() {
+    // The rejection keys depend on z only, with z == d an implementation reading the wrong seed would still match
+    assert_ne!(D, Z);
+
+    match std::env::args().nth(1).as_deref() {
+        Some("variants") => {
+            variant_vectors::<Final512, Final768, Final1024>("FINAL");
+            variant_vectors::<Ipd512, Ipd768, Ipd1024>("IPD");
+        },
+        // One entry of KYBER, run once per parameter set (see README.md)
+        Some("kyber") => variant_vector::<Kyber>(),
+        Some("rejection") => {
+            rejection_vectors::<Final512>("MLKEM512");
+            rejection_vectors::<Final768>("MLKEM768");
+            rejection_vectors::<Final1024>("MLKEM1024");
+        },
+        _ => {
+            eprintln!("usage: refvectors <variants|kyber|rejection>");
+            std::process::exit(1);
+        }
+    }
+}